@@ -1,4 +1,5 @@
 use bip39::{Language, Mnemonic};
+use bip39_cli::security;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use rand::{rngs::OsRng, RngCore};
 
@@ -42,6 +43,31 @@ fn bench_validate_mnemonic(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_checksum_only(c: &mut Criterion) {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+    let mut group = c.benchmark_group("checksum_only");
+
+    group.bench_function("full_parse_24_words", |b| {
+        b.iter(|| {
+            let parsed = Mnemonic::parse_in_normalized(Language::English, mnemonic).unwrap();
+            black_box(parsed.to_entropy());
+        })
+    });
+
+    group.bench_function("checksum_only_24_words", |b| {
+        b.iter(|| {
+            black_box(security::verify_checksum(
+                black_box(mnemonic),
+                Language::English,
+            ))
+            .unwrap();
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_mnemonic_to_seed(c: &mut Criterion) {
     let mnemonic = Mnemonic::parse_in_normalized(
         Language::English,
@@ -112,10 +138,36 @@ fn bench_languages(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_find_invalid_words(c: &mut Criterion) {
+    let test_phrases = [
+        ("24_words_valid", "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold"),
+        ("24_words_4_typos", "voide come effort sufer camp survey warrior heavy shoot primary clutch crush open amazin screen patrol group space point ten exist slush involv unfold"),
+    ];
+
+    let mut group = c.benchmark_group("find_invalid_words");
+
+    for (name, phrase) in &test_phrases {
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                black_box(security::find_invalid_words(
+                    phrase,
+                    Language::English,
+                    security::DEFAULT_SUGGESTIONS,
+                    security::DEFAULT_MAX_EDIT_DISTANCE,
+                ))
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_generate_mnemonic,
     bench_validate_mnemonic,
+    bench_checksum_only,
+    bench_find_invalid_words,
     bench_mnemonic_to_seed,
     bench_entropy_operations,
     bench_languages
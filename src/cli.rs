@@ -1,4 +1,7 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Generator, Shell};
 
 #[derive(Parser)]
@@ -18,6 +21,89 @@ pub struct Cli {
     /// Show security recommendations and environment check
     #[arg(long, global = true)]
     pub security_check: bool,
+
+    /// With --security-check, also probe whether DNS servers are actually reachable
+    #[arg(long, global = true, requires = "security_check")]
+    pub deep: bool,
+
+    #[command(flatten)]
+    pub global: GlobalArgs,
+}
+
+/// Options shared by all subcommands
+#[derive(Args, Debug, Default)]
+pub struct GlobalArgs {
+    /// Write the primary result (mnemonic or seed) to a file with owner-only permissions
+    #[arg(long, global = true, value_name = "PATH")]
+    pub output_file: Option<PathBuf>,
+
+    /// Overwrite the output file if it already exists
+    #[arg(long, global = true, requires = "output_file")]
+    pub force: bool,
+
+    /// Answer yes to confirmation prompts, e.g. overwriting an output file.
+    /// Anything a prompt would have stopped proceeds unreviewed; secure input still prompts
+    #[arg(short = 'y', long, global = true)]
+    pub assume_yes: bool,
+
+    /// Abort hidden (secure) input if nothing is entered within SECS seconds
+    #[arg(long, global = true, value_name = "SECS", value_parser = parse_timeout)]
+    pub input_timeout: Option<Duration>,
+
+    /// Maximum number of suggestions shown for a misspelled word
+    #[arg(long, global = true, value_name = "N", default_value_t = crate::security::DEFAULT_SUGGESTIONS)]
+    pub suggestions: usize,
+
+    /// Only suggest words within N edits (insertions, deletions or substitutions) of a misspelling
+    #[arg(long, global = true, value_name = "N", default_value_t = crate::security::DEFAULT_MAX_EDIT_DISTANCE)]
+    pub max_edit_distance: usize,
+
+    /// Print wall-clock time spent in parsing, normalization and seed derivation to stderr
+    #[arg(long, global = true)]
+    pub profile: bool,
+
+    /// With --quiet, print this string directly before the result (e.g. a sentinel for extraction)
+    #[arg(long, global = true, value_name = "TEXT", allow_hyphen_values = true)]
+    pub output_prefix: Option<String>,
+
+    /// With --quiet, print this string directly after the result
+    #[arg(long, global = true, value_name = "TEXT", allow_hyphen_values = true)]
+    pub output_suffix: Option<String>,
+
+    /// Lock entropy, seed and passphrase buffers into RAM (mlock) so they are never swapped
+    /// to disk; warns and continues unlocked if the system refuses
+    #[arg(long, global = true)]
+    pub memory_lock: bool,
+
+    /// Letter case of printed hex (entropy, seeds, fingerprints); input accepts either
+    #[arg(long, global = true, value_enum, default_value = "lower")]
+    pub hex_case: HexCase,
+
+    /// Report failures on stderr as one JSON object (error_type, fields, exit_code);
+    /// the exit status is unchanged
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
+    /// Refuse to print mnemonics, seeds or entropy unless the stream they go to (stdout, or
+    /// stderr for generate --hash-output) is a terminal, so a secret is never piped into a log
+    #[arg(long, global = true)]
+    pub require_tty: bool,
+}
+
+/// How a mnemonic is laid out on screen; --quiet and --output-file always get the plain phrase
+#[derive(Args, Debug, Default, Clone, Copy)]
+pub struct WordLayout {
+    /// Prefix each word with its 1-based position
+    #[arg(long)]
+    pub numbered: bool,
+
+    /// Arrange the words in N columns, filled top to bottom
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(1..=24))]
+    pub columns: Option<u8>,
+
+    /// Mask each word to its first letter plus asterisks; the full phrase is not shown
+    #[arg(long, conflicts_with = "output_file")]
+    pub redact: bool,
 }
 
 #[derive(Subcommand)]
@@ -25,33 +111,184 @@ pub enum Commands {
     /// Generate a new mnemonic phrase
     Generate {
         /// Number of words in the mnemonic (12, 15, 18, 21, or 24)
-        #[arg(short, long)]
-        words: WordCount,
+        #[arg(short, long, required_unless_present_any = ["entropy_source", "strength"])]
+        words: Option<WordCount>,
+
+        /// Entropy size in bits (128, 160, 192, 224, or 256), as an alternative to --words
+        #[arg(long, value_name = "BITS", value_parser = parse_strength, conflicts_with = "words")]
+        strength: Option<WordCount>,
+
+        /// Entropy source: os, hex:<hexstring>, or file:<path> (hex and file infer the word count)
+        #[arg(long, value_name = "SOURCE", default_value = "os", value_parser = parse_entropy_source)]
+        entropy_source: EntropySource,
+
+        /// TESTING ONLY: derive entropy from SHA-256 of STRING, so the same string always
+        /// gives the same mnemonic. Anyone who knows or guesses STRING has the wallet
+        #[arg(
+            long,
+            hide = true,
+            value_name = "STRING",
+            conflicts_with_all = ["entropy_source", "avoid_words", "no_repeats"]
+        )]
+        deterministic: Option<String>,
+
+        /// XOR these hex bytes (e.g. from dice) into the drawn entropy; lengths must match,
+        /// and the result stays uniform as long as either source is random
+        #[arg(long, value_name = "HEX", conflicts_with = "deterministic")]
+        extra_entropy: Option<String>,
 
         /// Language for the mnemonic
         #[arg(short, long, default_value = "english")]
         language: LanguageOption,
 
+        /// Redraw entropy until the mnemonic contains none of these words (repeatable)
+        #[arg(
+            long = "avoid-word",
+            value_name = "WORD",
+            conflicts_with = "entropy_source"
+        )]
+        avoid_words: Vec<String>,
+
+        /// Redraw entropy until every word in the mnemonic is different
+        #[arg(long, conflicts_with = "entropy_source")]
+        no_repeats: bool,
+
         /// Show entropy used to generate the mnemonic
-        #[arg(long)]
+        #[arg(long, conflicts_with = "redact")]
         show_entropy: bool,
 
+        /// With --show-entropy, also show how entropy and checksum bits split into 11-bit word indices
+        #[arg(long, requires = "show_entropy")]
+        verbose_encoding: bool,
+
+        /// With --show-entropy, also show how often each hex digit occurs (not shown with --quiet)
+        #[arg(long, requires = "show_entropy")]
+        histogram: bool,
+
+        /// With --show-entropy, write only the raw entropy bytes to stdout (implies --quiet);
+        /// the mnemonic is not printed but still goes to --output-file or --out-fd
+        #[arg(
+            long,
+            requires = "show_entropy",
+            conflicts_with_all = ["verbose_encoding", "histogram", "show_seed", "copy"]
+        )]
+        raw: bool,
+
         /// Show seed derived from the mnemonic
-        #[arg(long)]
+        #[arg(long, conflicts_with = "redact")]
         show_seed: bool,
 
-        /// Passphrase for seed derivation (only used with --show-seed)
+        /// Print only the derived seed; the mnemonic is never shown, so keep the seed safe
+        #[arg(long, conflicts_with_all = ["show_entropy", "analyze_entropy", "numbered", "columns", "redact"])]
+        seed_only: bool,
+
+        /// Encoding for the seed output
+        #[arg(long, value_enum, default_value = "hex")]
+        seed_format: SeedFormat,
+
+        /// With --seed-only, encrypt the seed under the random bytes of this key file
+        /// before writing it to --output-file
+        #[arg(long, value_name = "KEYFILE", conflicts_with = "seed_format")]
+        encrypt_to: Option<PathBuf>,
+
+        /// Copy the mnemonic to the clipboard instead of printing it, then clear it after a timeout
+        #[arg(long, conflicts_with_all = ["seed_only", "redact"])]
+        copy: bool,
+
+        /// Write the mnemonic to this inherited file descriptor (Unix) instead of stdout
+        #[arg(
+            long,
+            value_name = "FD",
+            conflicts_with_all = ["output_file", "copy", "redact", "seed_only"]
+        )]
+        out_fd: Option<i32>,
+
+        /// Seconds before the copied mnemonic is wiped from the clipboard
+        #[arg(
+            long,
+            value_name = "SECS",
+            default_value = "30",
+            value_parser = parse_timeout,
+            requires = "copy"
+        )]
+        clipboard_timeout: Duration,
+
+        /// Passphrase for seed derivation (only used with --show-seed or --seed-only)
         #[arg(long, default_value = "")]
         passphrase: String,
 
+        /// Read the passphrase from the named environment variable instead of argv
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+
+        /// Read the passphrase from a file; one trailing newline is dropped, other whitespace kept
+        #[arg(long, value_name = "PATH")]
+        passphrase_file: Option<PathBuf>,
+
         /// Use secure input for passphrase (hidden from terminal)
         #[arg(long)]
         secure_passphrase: bool,
 
+        /// Type the secure passphrase twice; mismatches are re-prompted, then refused
+        #[arg(long, requires = "secure_passphrase")]
+        passphrase_confirm: bool,
+
         /// Analyze and display entropy quality assessment
         #[arg(long)]
         analyze_entropy: bool,
 
+        /// Minimum passphrase strength score (0.0-1.0) required when the passphrase is assessed
+        #[arg(long, value_name = "SCORE", default_value_t = 0.6, value_parser = parse_score)]
+        min_passphrase_score: f64,
+
+        /// Accept the passphrase regardless of its strength score
+        #[arg(long, conflicts_with = "min_passphrase_score")]
+        no_passphrase_check: bool,
+
+        /// Check the options and report what would be generated, without drawing
+        /// entropy, deriving a seed, or printing any secret
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Walk through entropy, its mapping to words, and the derived seed, labeling each
+        #[arg(
+            long,
+            conflicts_with_all = ["quiet", "seed_only", "raw", "copy", "out_fd", "output_file", "numbered", "columns", "redact"]
+        )]
+        explain: bool,
+
+        /// Print only a salted HMAC-SHA256 commitment to the mnemonic on stdout; the mnemonic
+        /// goes to --output-file, --out-fd or --copy, or to stderr if none is given
+        #[arg(
+            long,
+            conflicts_with_all = ["explain", "raw", "seed_only", "show_entropy", "show_seed", "numbered", "columns", "redact"]
+        )]
+        hash_output: bool,
+
+        /// With --hash-output, use this hex salt instead of 32 random bytes
+        #[arg(long, value_name = "HEX", requires = "hash_output")]
+        hash_salt: Option<String>,
+
+        /// Generate this many independent mnemonics, one per line
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u32).range(1..=10_000),
+            conflicts_with_all = [
+                "entropy_source", "deterministic", "show_entropy", "show_seed", "seed_only",
+                "copy", "out_fd", "analyze_entropy", "explain", "hash_output", "hash_salt",
+                "raw", "encrypt_to", "numbered", "columns", "redact"
+            ]
+        )]
+        count: Option<u32>,
+
+        /// With --count, guarantee no two mnemonics are the same, redrawing any repeat
+        #[arg(long, requires = "count")]
+        unique: bool,
+
+        #[command(flatten)]
+        layout: WordLayout,
+
         /// Output only raw data without headers (useful for piping)
         #[arg(short, long)]
         quiet: bool,
@@ -60,7 +297,28 @@ pub enum Commands {
     /// Validate a mnemonic phrase
     Validate {
         /// The mnemonic phrase to validate (space-separated words)
-        mnemonic: String,
+        #[arg(required_unless_present_any = ["secure_input", "input_file", "stdin_list"])]
+        mnemonic: Option<String>,
+
+        /// Read the mnemonic from a file instead of the command line
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["mnemonic", "secure_input"])]
+        input_file: Option<PathBuf>,
+
+        /// Validate one mnemonic per line read from stdin and print a summary
+        #[arg(long, conflicts_with_all = ["mnemonic", "input_file", "secure_input", "show_normalized"])]
+        stdin_list: bool,
+
+        /// With --stdin-list, records are separated by NUL bytes instead of newlines
+        #[arg(long, requires = "stdin_list", conflicts_with_all = ["mnemonic", "input_file", "secure_input"])]
+        null: bool,
+
+        /// With --stdin-list, reject otherwise valid phrases shorter than this
+        #[arg(long, value_name = "N", requires = "stdin_list")]
+        min_words: Option<WordCount>,
+
+        /// With --stdin-list, reject otherwise valid phrases longer than this
+        #[arg(long, value_name = "N", requires = "stdin_list")]
+        max_words: Option<WordCount>,
 
         /// Language of the mnemonic
         #[arg(short, long, default_value = "english")]
@@ -70,6 +328,35 @@ pub enum Commands {
         #[arg(long)]
         secure_input: bool,
 
+        /// Print the normalized form of the mnemonic that is actually parsed, and flag
+        /// invisible or look-alike characters in the input
+        #[arg(long)]
+        show_normalized: bool,
+
+        /// Mask words in the output (normalized form, invalid words) to their first letter
+        #[arg(long)]
+        redact: bool,
+
+        /// Only check word membership and checksum bits (faster, no report)
+        #[arg(long)]
+        checksum_only: bool,
+
+        /// Also report the word list size, its script, and whether NFKD normalization changed the input
+        #[arg(long, conflicts_with_all = ["quiet", "stdin_list", "words_file"])]
+        verbose: bool,
+
+        /// Warn (without failing) about words repeated more often than chance explains
+        #[arg(long, conflicts_with = "stdin_list")]
+        warn_duplicates: bool,
+
+        /// Also require exactly this many words, to catch a dropped word that leaves a valid shorter phrase
+        #[arg(long, value_name = "N", conflicts_with = "stdin_list")]
+        expected_word_count: Option<WordCount>,
+
+        /// Check the mnemonic against a custom list of 2048 words (one per line); not standard BIP39
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["language", "stdin_list", "checksum_only", "show_normalized", "secure_input"])]
+        words_file: Option<PathBuf>,
+
         /// Output only raw data without headers (useful for piping)
         #[arg(short, long)]
         quiet: bool,
@@ -78,25 +365,126 @@ pub enum Commands {
     /// Convert mnemonic to seed
     Seed {
         /// The mnemonic phrase (space-separated words)
-        mnemonic: String,
+        #[arg(required_unless_present_any = ["secure_input", "input_file"])]
+        mnemonic: Option<String>,
+
+        /// Read the mnemonic from a file instead of the command line
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["mnemonic", "secure_input"])]
+        input_file: Option<PathBuf>,
 
         /// Passphrase for seed derivation
         #[arg(short, long, default_value = "")]
         passphrase: String,
 
+        /// Read the passphrase from the named environment variable instead of argv
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+
+        /// Read the passphrase from a file; one trailing newline is dropped, other whitespace kept
+        #[arg(long, value_name = "PATH")]
+        passphrase_file: Option<PathBuf>,
+
         /// Use secure input for both mnemonic and passphrase
         #[arg(long)]
         secure_input: bool,
 
+        /// Type the secure passphrase twice; mismatches are re-prompted, then refused
+        #[arg(long, requires = "secure_input")]
+        passphrase_confirm: bool,
+
         /// Assess and display passphrase strength
         #[arg(long)]
         analyze_passphrase: bool,
 
-        /// Language of the mnemonic
+        /// Minimum passphrase strength score (0.0-1.0) required when the passphrase is assessed
+        #[arg(long, value_name = "SCORE", default_value_t = 0.6, value_parser = parse_score)]
+        min_passphrase_score: f64,
+
+        /// Accept the passphrase regardless of its strength score
+        #[arg(long, conflicts_with = "min_passphrase_score")]
+        no_passphrase_check: bool,
+
+        /// Print the NFKD-normalized mnemonic and passphrase fed into PBKDF2
+        #[arg(long, conflicts_with = "scheme")]
+        show_normalized: bool,
+
+        /// Print the PBKDF2 salt, iteration count and HMAC used; the passphrase is masked
+        #[arg(long, conflicts_with = "scheme")]
+        explain: bool,
+
+        /// With --explain, show the passphrase inside the salt instead of masking it
+        #[arg(long, requires = "explain")]
+        reveal: bool,
+
+        /// PBKDF2 iteration count; BIP39 fixes this at 2048 and other values are rejected
+        #[arg(long, value_name = "ROUNDS", conflicts_with = "scheme")]
+        kdf_rounds: Option<u32>,
+
+        /// Derivation scheme; cardano-icarus derives a Cardano master key from the entropy
+        #[arg(long, value_enum, default_value = "bip39")]
+        scheme: SeedScheme,
+
+        /// Encoding for the seed output
+        #[arg(long, value_enum, default_value = "hex")]
+        seed_format: SeedFormat,
+
+        /// Encrypt the seed under the random bytes of this key file before writing it to --output-file
+        #[arg(long, value_name = "KEYFILE", conflicts_with = "seed_format")]
+        encrypt_to: Option<PathBuf>,
+
+        /// Language of the mnemonic, or "auto" to detect it from the words
+        #[arg(short, long, default_value = "english", value_parser = parse_language_choice)]
+        language: LanguageChoice,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Decrypt a seed written with --encrypt-to
+    Decrypt {
+        /// File holding the encrypted seed
+        path: PathBuf,
+
+        /// Key file the seed was encrypted with
+        #[arg(long, value_name = "KEYFILE")]
+        keyfile: PathBuf,
+
+        /// Encoding for the seed output
+        #[arg(long, value_enum, default_value = "hex")]
+        seed_format: SeedFormat,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Derive a mnemonic from the sensor noise in a photo
+    ///
+    /// The pixel bytes (or only their least-significant bits) are hashed with
+    /// SHA-256. The result is only as unpredictable as the photo; prefer a
+    /// fresh, dark, high-ISO shot taken for this purpose and never shared.
+    FromImage {
+        /// Image file (PNG, JPEG or BMP)
+        path: PathBuf,
+
+        /// Number of words in the mnemonic
+        #[arg(short, long, default_value = "24")]
+        words: WordCount,
+
+        /// Hash only the least-significant bit of each sample, where sensor noise lives
+        #[arg(long)]
+        lsb: bool,
+
+        /// Refuse the image if the hashed material scores below this threshold (0.0-1.0)
+        #[arg(long, value_name = "SCORE", value_parser = parse_score)]
+        min_entropy_score: Option<f64>,
+
+        /// Language of the word list
         #[arg(short, long, default_value = "english")]
         language: LanguageOption,
 
-        /// Output only raw data without headers (useful for piping)
+        /// Output only the mnemonic without headers (useful for piping)
         #[arg(short, long)]
         quiet: bool,
     },
@@ -104,7 +492,36 @@ pub enum Commands {
     /// Generate mnemonic from provided entropy
     FromEntropy {
         /// Entropy as hex string (32, 40, 48, 56, or 64 hex chars for 12, 15, 18, 21, or 24 words)
-        entropy: String,
+        #[arg(required_unless_present = "input_file")]
+        entropy: Option<String>,
+
+        /// Read the hex entropy from a file instead of the command line
+        #[arg(long, value_name = "PATH", conflicts_with = "entropy")]
+        input_file: Option<PathBuf>,
+
+        /// Refuse entropy whose quality score (0.0-1.0) is below this threshold
+        #[arg(long, value_name = "SCORE", value_parser = parse_score)]
+        min_entropy_score: Option<f64>,
+
+        /// Byte order of the entropy hex; little reverses the bytes before conversion
+        #[arg(long, value_enum, default_value = "big")]
+        byte_order: ByteOrder,
+
+        /// UNSAFE: zero-pad entropy shorter than a valid length up to the next one; the
+        /// padding adds no randomness, so the mnemonic is only as strong as the given digits
+        #[arg(long, value_enum, value_name = "SIDE")]
+        pad_entropy: Option<PadSide>,
+
+        /// Spell the mnemonic with a custom list of 2048 words (one per line); not standard BIP39
+        #[arg(long, value_name = "PATH", conflicts_with = "language")]
+        words_file: Option<PathBuf>,
+
+        /// Print the mnemonic in every supported language, one labeled line each
+        #[arg(long, conflicts_with_all = ["language", "words_file", "numbered", "columns"])]
+        all_languages: bool,
+
+        #[command(flatten)]
+        layout: WordLayout,
 
         /// Language for the mnemonic
         #[arg(short, long, default_value = "english")]
@@ -115,11 +532,214 @@ pub enum Commands {
         quiet: bool,
     },
 
+    /// Build a mnemonic from its 11-bit word indices (0-2047)
+    FromIndices {
+        /// Word indices separated by commas or spaces, e.g. "0,0,0,0,0,0,0,0,0,0,0,3"
+        #[arg(required_unless_present = "grid_file")]
+        indices: Option<String>,
+
+        /// Read a metal-backup grid: one "<position> <index>" line per word, positions from 1
+        #[arg(long, value_name = "PATH", conflicts_with = "indices")]
+        grid_file: Option<PathBuf>,
+
+        /// Language of the word list
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
     /// Get entropy from a mnemonic
     Entropy {
+        /// The mnemonic phrase (space-separated words)
+        #[arg(required_unless_present = "input_file")]
+        mnemonic: Option<String>,
+
+        /// Read the mnemonic from a file instead of the command line
+        #[arg(long, value_name = "PATH", conflicts_with = "mnemonic")]
+        input_file: Option<PathBuf>,
+
+        /// Print the 11-bit word index (0-2047) of each word instead of the entropy
+        #[arg(long)]
+        show_indices: bool,
+
+        /// Byte order of the printed entropy hex; little reverses the bytes
+        #[arg(
+            long,
+            value_enum,
+            default_value = "big",
+            conflicts_with = "show_indices"
+        )]
+        byte_order: ByteOrder,
+
+        /// Also show the SHA-256 checksum bits and check them against the last word
+        #[arg(long, conflicts_with = "show_indices")]
+        show_checksum: bool,
+
+        /// Mask the entropy hex to its first digit plus asterisks
+        #[arg(long, conflicts_with_all = ["show_indices", "show_checksum", "output_file"])]
+        redact: bool,
+
+        /// Write the entropy as raw bytes to stdout, with no hex, headers or newline
+        #[arg(long, conflicts_with_all = ["show_indices", "show_checksum", "redact"])]
+        raw: bool,
+
+        /// Print the entropy as six-sided dice rolls (1-6), read as a base-6 number
+        #[arg(long, conflicts_with_all = ["show_indices", "byte_order", "show_checksum", "redact", "raw"])]
+        as_dice: bool,
+
+        /// Read the mnemonic with a custom list of 2048 words (one per line); not standard BIP39
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["language", "show_indices", "show_checksum"])]
+        words_file: Option<PathBuf>,
+
+        /// Language of the mnemonic
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Assess the strength of a candidate passphrase
+    AnalyzePassphrase {
+        /// The passphrase to analyze
+        #[arg(required_unless_present = "secure_input")]
+        passphrase: Option<String>,
+
+        /// Use secure input for the passphrase (hidden from terminal)
+        #[arg(long)]
+        secure_input: bool,
+
+        /// Output only the numeric score (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Assess the quality of externally sourced entropy
+    AnalyzeEntropy {
+        /// Entropy as hex string (32, 40, 48, 56, or 64 hex chars)
+        entropy: String,
+
+        /// Output only the numeric score (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Run the entropy quality analyzer over many OsRng samples
+    #[command(hide = true)]
+    SelfTest {
+        /// Number of random samples to analyze
+        #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..=100_000))]
+        samples: u32,
+
+        /// Size of each sample in bytes
+        #[arg(long, default_value_t = 32, value_parser = clap::value_parser!(u32).range(1..=1024))]
+        size: u32,
+
+        /// Output only the mean score (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Print a complete word list exactly as this tool uses it
+    #[command(hide = true)]
+    Dump {
+        /// Language of the word list
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Prefix each word with its zero-based index
+        #[arg(long)]
+        with_index: bool,
+
+        /// Print indices in hex (0x000-0x7ff) instead of decimal
+        #[arg(long, requires = "with_index")]
+        hex_index: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: DumpFormat,
+    },
+
+    /// Look up word list entries by prefix or index
+    Words {
+        /// List words starting with this prefix (use "" for the whole word list)
+        #[arg(long, required_unless_present = "index", conflicts_with = "index")]
+        prefix: Option<String>,
+
+        /// Print the word at this zero-based index (0-2047)
+        #[arg(long)]
+        index: Option<usize>,
+
+        /// Language of the word list
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// List the supported word list languages and their --language values
+    Languages {
+        /// Print only the --language values, one per line
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Check the embedded word lists against their known SHA-256 digests
+    Integrity {
+        /// Print only "ok" on success
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Run the official BIP39 test vectors against this build
+    Compliance {
+        /// Print only "ok" on success
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Time generate, validate, seed and entropy on this machine
+    Bench {
+        /// Number of words in the benchmarked mnemonic
+        #[arg(short, long, default_value = "12")]
+        words: WordCount,
+
+        /// Language of the benchmarked mnemonic
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Times each operation is run
+        #[arg(long, default_value_t = 200, value_parser = clap::value_parser!(u32).range(1..=1_000_000))]
+        iterations: u32,
+
+        /// Output only "operation<TAB>ops/sec" lines (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Derive a Bitcoin address from a mnemonic
+    Address {
         /// The mnemonic phrase (space-separated words)
         mnemonic: String,
 
+        /// Passphrase for seed derivation
+        #[arg(short, long, default_value = "")]
+        passphrase: String,
+
+        /// Derivation purpose (44 = P2PKH, 49 = P2SH-P2WPKH, 84 = P2WPKH, 86 = P2TR)
+        #[arg(long, default_value = "84")]
+        purpose: AddressPurpose,
+
+        /// Address index on the external chain
+        #[arg(long, default_value_t = 0)]
+        index: u32,
+
         /// Language of the mnemonic
         #[arg(short, long, default_value = "english")]
         language: LanguageOption,
@@ -128,6 +748,270 @@ pub enum Commands {
         #[arg(short, long)]
         quiet: bool,
     },
+
+    /// Build the BIP32 root key directly from a 64-byte seed, without a mnemonic
+    SeedToXprv {
+        /// The 64-byte seed as 128 hex characters
+        #[arg(long, value_name = "HEX")]
+        seed: String,
+
+        /// Network whose key version bytes to use (xprv/xpub or tprv/tpub)
+        #[arg(long, value_enum, default_value = "bitcoin")]
+        network: NetworkOption,
+
+        /// Output only the xprv without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Print the BIP32 master key fingerprint of a mnemonic
+    Fingerprint {
+        /// The mnemonic phrase (space-separated words)
+        mnemonic: String,
+
+        /// Passphrase for seed derivation
+        #[arg(short, long, default_value = "")]
+        passphrase: String,
+
+        /// Language of the mnemonic
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Summarize a mnemonic: word count, language, entropy size and master fingerprint
+    Info {
+        /// The mnemonic phrase (space-separated words)
+        mnemonic: String,
+
+        /// Passphrase for seed derivation (changes the fingerprint)
+        #[arg(short, long, default_value = "")]
+        passphrase: String,
+
+        /// Read the passphrase from the named environment variable instead of argv
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+
+        /// Read the passphrase from a file; one trailing newline is dropped, other whitespace kept
+        #[arg(long, value_name = "PATH")]
+        passphrase_file: Option<PathBuf>,
+
+        /// Language of the mnemonic, or "auto" to detect it from the words
+        #[arg(short, long, default_value = "auto", value_parser = parse_language_choice)]
+        language: LanguageChoice,
+
+        /// Print one tab-separated line: words, language, entropy bits, fingerprint, passphrase
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Estimate a backup's combined strength: mnemonic entropy plus passphrase entropy
+    Strength {
+        /// The mnemonic phrase (space-separated words)
+        mnemonic: String,
+
+        /// Passphrase used with the mnemonic, if any
+        #[arg(short, long, default_value = "")]
+        passphrase: String,
+
+        /// Read the passphrase from the named environment variable instead of argv
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+
+        /// Read the passphrase from a file; one trailing newline is dropped, other whitespace kept
+        #[arg(long, value_name = "PATH")]
+        passphrase_file: Option<PathBuf>,
+
+        /// Language of the mnemonic, or "auto" to detect it from the words
+        #[arg(short, long, default_value = "auto", value_parser = parse_language_choice)]
+        language: LanguageChoice,
+
+        /// Print only the effective entropy in bits
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Compare two mnemonics word by word
+    Diff {
+        /// The original mnemonic phrase
+        first: String,
+
+        /// The mnemonic phrase to compare against it
+        second: String,
+
+        /// Language of the mnemonics
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Output only raw data without headers (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Check that a mnemonic reproduces an expected seed or fingerprint (prints match/mismatch)
+    Verify {
+        /// The mnemonic phrase (space-separated words)
+        mnemonic: String,
+
+        /// Passphrase for seed derivation
+        #[arg(short, long, default_value = "")]
+        passphrase: String,
+
+        /// Expected 64-byte seed as hex
+        #[arg(
+            long,
+            value_name = "HEX",
+            required_unless_present = "expected_fingerprint"
+        )]
+        expected_seed: Option<String>,
+
+        /// Expected 4-byte BIP32 master fingerprint as hex
+        #[arg(long, value_name = "HEX", conflicts_with = "expected_seed")]
+        expected_fingerprint: Option<String>,
+
+        /// Language of the mnemonic
+        #[arg(short, long, default_value = "english")]
+        language: LanguageOption,
+
+        /// Print only match/mismatch (useful for piping)
+        #[arg(short, long)]
+        quiet: bool,
+    },
+}
+
+/// Parse a strength score in the range 0.0-1.0
+fn parse_score(s: &str) -> Result<f64, String> {
+    let score: f64 = s.parse().map_err(|_| format!("'{s}' is not a number"))?;
+    if (0.0..=1.0).contains(&score) {
+        Ok(score)
+    } else {
+        Err(format!("score must be between 0.0 and 1.0, got {score}"))
+    }
+}
+
+/// Parse a whole number of seconds greater than zero
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    match s.parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+        _ => Err(format!("'{s}' is not a positive number of seconds")),
+    }
+}
+
+/// Text encoding for a derived seed
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum SeedFormat {
+    Hex,
+    Base64,
+    Base58,
+}
+
+/// Output format of the `dump` command
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum DumpFormat {
+    /// One word per line
+    Text,
+    /// A JSON array of words, or of {"index", "word"} objects with --with-index
+    Json,
+}
+
+/// What `seed` derives from the mnemonic
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum SeedScheme {
+    /// Standard BIP39 seed: PBKDF2 over the mnemonic sentence
+    Bip39,
+    /// Cardano Icarus master key: PBKDF2 over the entropy (CIP-3)
+    CardanoIcarus,
+}
+
+/// Byte order of entropy hex, for tools that export it reversed
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum ByteOrder {
+    /// Most significant byte first, as in BIP39 test vectors
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+/// Where `from-entropy --pad-entropy` adds zero digits to short entropy
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum PadSide {
+    /// Zeros before the given digits, as when leading zeros were dropped
+    Left,
+    /// Zeros after the given digits
+    Right,
+}
+
+/// Letter case of printed hex, to match tools that print it uppercase
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug, Default)]
+pub enum HexCase {
+    /// 0-9 and a-f, as in the BIP39 test vectors
+    #[default]
+    Lower,
+    /// 0-9 and A-F
+    Upper,
+}
+
+impl HexCase {
+    /// Hex-encode `bytes` in this case
+    #[must_use]
+    pub fn encode(self, bytes: impl AsRef<[u8]>) -> String {
+        match self {
+            Self::Lower => hex::encode(bytes),
+            Self::Upper => hex::encode_upper(bytes),
+        }
+    }
+}
+
+/// Where `generate` takes its entropy from
+#[derive(Clone, Debug)]
+pub enum EntropySource {
+    Os,
+    Hex(String),
+    File(PathBuf),
+}
+
+fn parse_entropy_source(s: &str) -> Result<EntropySource, String> {
+    if s == "os" {
+        Ok(EntropySource::Os)
+    } else if let Some(hex) = s.strip_prefix("hex:") {
+        Ok(EntropySource::Hex(hex.to_string()))
+    } else if let Some(path) = s.strip_prefix("file:") {
+        Ok(EntropySource::File(PathBuf::from(path)))
+    } else {
+        Err("expected 'os', 'hex:<hexstring>' or 'file:<path>'".to_string())
+    }
+}
+
+/// Parse a `--language` value that may also be "auto"
+fn parse_language_choice(s: &str) -> Result<LanguageChoice, String> {
+    if s.eq_ignore_ascii_case("auto") {
+        return Ok(LanguageChoice::Auto);
+    }
+    LanguageOption::from_str(s, true)
+        .map(LanguageChoice::Fixed)
+        .map_err(|_| {
+            let names: Vec<String> = LanguageOption::value_variants()
+                .iter()
+                .filter_map(|language| language.to_possible_value())
+                .map(|value| value.get_name().to_string())
+                .collect();
+            format!(
+                "'{s}' is not a language; use auto or one of: {}",
+                names.join(", ")
+            )
+        })
+}
+
+/// Map an entropy size in bits to the matching word count
+fn parse_strength(s: &str) -> Result<WordCount, String> {
+    WordCount::value_variants()
+        .iter()
+        .copied()
+        .find(|count| s.parse() == Ok(count.to_entropy_bits()))
+        .ok_or_else(|| format!("'{s}' is not a BIP39 strength (128, 160, 192, 224, or 256)"))
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -173,20 +1057,121 @@ impl WordCount {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+pub enum AddressPurpose {
+    #[value(name = "44")]
+    Legacy,
+    #[value(name = "49")]
+    NestedSegwit,
+    #[value(name = "84")]
+    NativeSegwit,
+    #[value(name = "86")]
+    Taproot,
+}
+
+impl AddressPurpose {
+    #[must_use]
+    pub const fn to_purpose_number(self) -> u32 {
+        match self {
+            Self::Legacy => 44,
+            Self::NestedSegwit => 49,
+            Self::NativeSegwit => 84,
+            Self::Taproot => 86,
+        }
+    }
+
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Legacy => "Legacy (P2PKH)",
+            Self::NestedSegwit => "Nested SegWit (P2SH-P2WPKH)",
+            Self::NativeSegwit => "Native SegWit (P2WPKH)",
+            Self::Taproot => "Taproot (P2TR)",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum NetworkOption {
+    Bitcoin,
+    Testnet,
+    Signet,
+    Regtest,
+}
+
+impl From<NetworkOption> for bitcoin::Network {
+    fn from(network: NetworkOption) -> Self {
+        match network {
+            NetworkOption::Bitcoin => Self::Bitcoin,
+            NetworkOption::Testnet => Self::Testnet,
+            NetworkOption::Signet => Self::Signet,
+            NetworkOption::Regtest => Self::Regtest,
+        }
+    }
+}
+
+/// A `--language` that can be left to detection with "auto"
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LanguageChoice {
+    Auto,
+    Fixed(LanguageOption),
+}
+
+/// A BIP39 word list, also accepted by its ISO 639-1 code (`zh-hans`/`zh-hant` for Chinese)
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 pub enum LanguageOption {
+    #[value(alias = "en")]
     English,
+    #[value(alias = "ja")]
     Japanese,
+    #[value(alias = "ko")]
     Korean,
+    #[value(alias = "es")]
     Spanish,
+    #[value(alias = "zh-hans")]
     ChineseSimplified,
+    #[value(alias = "zh-hant")]
     ChineseTraditional,
+    #[value(alias = "fr")]
     French,
+    #[value(alias = "it")]
     Italian,
+    #[value(alias = "cs")]
     Czech,
+    #[value(alias = "pt")]
     Portuguese,
 }
 
+impl LanguageOption {
+    /// Human-readable language name
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Japanese => "Japanese",
+            Self::Korean => "Korean",
+            Self::Spanish => "Spanish",
+            Self::ChineseSimplified => "Chinese (Simplified)",
+            Self::ChineseTraditional => "Chinese (Traditional)",
+            Self::French => "French",
+            Self::Italian => "Italian",
+            Self::Czech => "Czech",
+            Self::Portuguese => "Portuguese",
+        }
+    }
+
+    /// Writing system of the word list
+    #[must_use]
+    pub const fn script(self) -> &'static str {
+        match self {
+            Self::Japanese => "Kana",
+            Self::Korean => "Hangul",
+            Self::ChineseSimplified | Self::ChineseTraditional => "Han",
+            _ => "Latin",
+        }
+    }
+}
+
 impl From<LanguageOption> for bip39::Language {
     fn from(lang: LanguageOption) -> Self {
         match lang {
@@ -0,0 +1,83 @@
+use std::thread;
+use std::time::Duration;
+
+use zeroize::Zeroize;
+
+use crate::error::CliError;
+
+/// The clipboard operations needed to copy a secret and later wipe it
+pub trait ClipboardText {
+    /// Current clipboard text, or `None` if it holds no text
+    fn get_text(&mut self) -> Option<String>;
+    fn set_text(&mut self, text: &str) -> Result<(), CliError>;
+    fn clear(&mut self) -> Result<(), CliError>;
+}
+
+impl ClipboardText for arboard::Clipboard {
+    fn get_text(&mut self) -> Option<String> {
+        arboard::Clipboard::get_text(self).ok()
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), CliError> {
+        arboard::Clipboard::set_text(self, text).map_err(clipboard_error)
+    }
+
+    fn clear(&mut self) -> Result<(), CliError> {
+        arboard::Clipboard::clear(self).map_err(clipboard_error)
+    }
+}
+
+fn clipboard_error(error: arboard::Error) -> CliError {
+    CliError::ClipboardError {
+        message: error.to_string(),
+    }
+}
+
+/// Open the system clipboard; fails on headless systems without one
+pub fn open_clipboard() -> Result<arboard::Clipboard, CliError> {
+    arboard::Clipboard::new().map_err(clipboard_error)
+}
+
+/// Wait `timeout`, then overwrite and clear the clipboard if it still holds `secret`
+///
+/// Returns `false` and leaves the clipboard alone if something else was copied
+/// in the meantime.
+pub fn clear_after(
+    clipboard: &mut impl ClipboardText,
+    secret: &str,
+    timeout: Duration,
+) -> Result<bool, CliError> {
+    thread::sleep(timeout);
+
+    let mut current = clipboard.get_text().unwrap_or_default();
+    let unchanged = current == secret;
+    current.zeroize();
+    if !unchanged {
+        return Ok(false);
+    }
+
+    // Overwrite first so clipboard managers that keep history see blanks, not the secret
+    clipboard.set_text(&" ".repeat(secret.len()))?;
+    clipboard.clear()?;
+    Ok(true)
+}
+
+/// Copy `secret` to the clipboard and block until it is cleared after `timeout`
+pub fn copy_with_auto_clear(
+    clipboard: &mut impl ClipboardText,
+    secret: &str,
+    timeout: Duration,
+) -> Result<(), CliError> {
+    clipboard.set_text(secret)?;
+    eprintln!(
+        "📋 Copied to clipboard; it will be cleared in {}s (keep this command running)",
+        timeout.as_secs()
+    );
+
+    if clear_after(clipboard, secret, timeout)? {
+        eprintln!("🧹 Clipboard cleared");
+    } else {
+        eprintln!("⚠️  Clipboard changed since copying; left untouched");
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use bip39::Mnemonic;
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv, Xpub};
+use bitcoin::key::TapTweak;
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{Address, KnownHrp, Network};
+use zeroize::Zeroize;
+
+use crate::cli::{AddressPurpose, GlobalArgs, LanguageOption};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{normalize_mnemonic, validate_mnemonic_word_count};
+
+/// Build the BIP44-style path `m/purpose'/0'/0'/0/index` for Bitcoin mainnet
+fn derivation_path(purpose: AddressPurpose, index: u32) -> Result<DerivationPath, CliError> {
+    Ok(DerivationPath::from(vec![
+        ChildNumber::from_hardened_idx(purpose.to_purpose_number())?,
+        ChildNumber::from_hardened_idx(0)?,
+        ChildNumber::from_hardened_idx(0)?,
+        ChildNumber::from_normal_idx(0)?,
+        ChildNumber::from_normal_idx(index)?,
+    ]))
+}
+
+pub fn handle_address(
+    mnemonic: String,
+    passphrase: String,
+    purpose: AddressPurpose,
+    index: u32,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mnemonic = normalize_mnemonic(&mnemonic, language.into());
+    validate_mnemonic_word_count(&mnemonic)?;
+    let mnemonic_obj = Mnemonic::parse_in_normalized(language.into(), &mnemonic)?;
+    let path = derivation_path(purpose, index)?;
+
+    let secp = Secp256k1::new();
+    let mut seed = mnemonic_obj.to_seed(&passphrase);
+    let master = Xpriv::new_master(Network::Bitcoin, &seed);
+    seed.zeroize(); // Clear seed from memory
+    let child = master?.derive_priv(&secp, &path)?;
+    let xpub = Xpub::from_priv(&secp, &child);
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Address Derivation")?;
+    out.field("Type", purpose.description())?;
+    out.field("Path", format_args!("m/{path}"))?;
+
+    let address = if purpose == AddressPurpose::Taproot {
+        // BIP86: key-path-only spend, so the output key is the internal key
+        // tweaked with an empty script tree (BIP341)
+        let internal_key = xpub.to_x_only_pub();
+        let (output_key, _parity) = internal_key.tap_tweak(&secp, None);
+        out.field("Internal key", internal_key)?;
+        out.field("Output key", output_key)?;
+        Address::p2tr_tweaked(output_key, KnownHrp::Mainnet)
+    } else {
+        let public_key = xpub.to_pub();
+        out.field("Public key", public_key)?;
+        match purpose {
+            AddressPurpose::Legacy => Address::p2pkh(public_key, Network::Bitcoin),
+            AddressPurpose::NestedSegwit => Address::p2shwpkh(&public_key, Network::Bitcoin),
+            _ => Address::p2wpkh(&public_key, KnownHrp::Mainnet),
+        }
+    };
+
+    out.blank()?;
+    out.data(address)?;
+
+    Ok(())
+}
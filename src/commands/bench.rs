@@ -0,0 +1,89 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bip39::{Language, Mnemonic};
+use rand::{rngs::OsRng, RngCore};
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageOption, WordCount};
+use crate::error::CliError;
+use crate::output::Output;
+
+/// Time `iterations` calls of `op`
+fn time_op(iterations: u32, mut op: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        op();
+    }
+    start.elapsed()
+}
+
+pub fn handle_bench(
+    words: WordCount,
+    language: LanguageOption,
+    iterations: u32,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let lang: Language = language.into();
+    let mut entropy = vec![0u8; words.to_entropy_bytes()];
+    OsRng.fill_bytes(&mut entropy);
+    let mnemonic = Mnemonic::from_entropy_in(lang, &entropy)?;
+    let mut phrase = mnemonic.to_string();
+
+    let timings = [
+        (
+            "generate",
+            time_op(iterations, || {
+                OsRng.fill_bytes(&mut entropy);
+                let _ = black_box(Mnemonic::from_entropy_in(lang, &entropy));
+            }),
+        ),
+        (
+            "validate",
+            time_op(iterations, || {
+                let _ = black_box(Mnemonic::parse_in_normalized(lang, &phrase));
+            }),
+        ),
+        (
+            "seed",
+            time_op(iterations, || {
+                black_box(mnemonic.to_seed_normalized(""));
+            }),
+        ),
+        (
+            "entropy",
+            time_op(iterations, || {
+                black_box(mnemonic.to_entropy());
+            }),
+        ),
+    ];
+    entropy.zeroize();
+    phrase.zeroize();
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Benchmark")?;
+    out.field("Words", words.to_word_count())?;
+    out.field("Language", language.display_name())?;
+    out.field("Iterations", iterations)?;
+    out.blank()?;
+    for (name, elapsed) in timings {
+        let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+        let ops_per_sec = f64::from(iterations) / secs;
+        if quiet {
+            out.data(format_args!("{name}\t{ops_per_sec:.0}"))?;
+        } else {
+            out.line(format_args!(
+                "{name:<10} {ops_per_sec:>12.0} ops/sec  {:>10.2} µs/op",
+                secs * 1e6 / f64::from(iterations)
+            ))?;
+        }
+    }
+    if !quiet {
+        out.blank()?;
+        out.line(
+            "seed runs PBKDF2-HMAC-SHA512 with 2048 rounds, so it dominates brute-force searches",
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::cli::GlobalArgs;
+use crate::compliance::{load_test_vectors, validate_test_vector, VECTOR_PASSPHRASE};
+use crate::error::CliError;
+use crate::output::Output;
+
+pub fn handle_compliance(global: &GlobalArgs, quiet: bool) -> Result<(), CliError> {
+    let vectors = load_test_vectors().map_err(|message| CliError::InputFileError {
+        path: "embedded test-vectors.json".to_string(),
+        message,
+        hint: "This binary may be corrupted; reinstall it from a trusted source".to_string(),
+    })?;
+    let failures: Vec<(usize, String)> = vectors
+        .iter()
+        .enumerate()
+        .filter_map(|(i, vector)| validate_test_vector(vector).err().map(|e| (i + 1, e)))
+        .collect();
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("BIP39 Compliance")?;
+    out.field(
+        "Vectors",
+        format_args!(
+            "{} (English, passphrase \"{VECTOR_PASSPHRASE}\")",
+            vectors.len()
+        ),
+    )?;
+    out.field("Passed", vectors.len() - failures.len())?;
+    out.field("Failed", failures.len())?;
+    for (number, message) in &failures {
+        out.blank()?;
+        out.line(format_args!("✗ Vector {number}: {message}"))?;
+    }
+
+    if !failures.is_empty() {
+        return Err(CliError::ComplianceFailed {
+            failed: failures.len(),
+            total: vectors.len(),
+        });
+    }
+    if quiet {
+        out.data("ok")?;
+    } else {
+        out.blank()?;
+        out.line("✓ This build reproduces every official test vector")?;
+    }
+    Ok(())
+}
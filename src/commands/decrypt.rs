@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, SeedFormat};
+use crate::encryption::{decrypt_seed, read_key_file};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::read_input_file;
+
+pub fn handle_decrypt(
+    path: &Path,
+    keyfile: &Path,
+    seed_format: SeedFormat,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let key = read_key_file(keyfile)?;
+    let encrypted = read_input_file(path)?;
+    let seed = decrypt_seed(&encrypted, &key, path)?;
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Decrypted Seed")?;
+    out.field(
+        "Output",
+        format_args!("{} bits ({} bytes)", seed.len() * 8, seed.len()),
+    )?;
+    out.blank()?;
+    let mut encoded_seed = super::seed::encode_seed(&seed, seed_format, global.hex_case);
+    let emitted = super::emit_secret(&mut out, &encoded_seed, global);
+    encoded_seed.zeroize();
+    emitted
+}
@@ -0,0 +1,111 @@
+use console::Style;
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageOption};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{normalize_mnemonic, split_entropy_checksum};
+
+/// Whether two differing phrases encode the same entropy and differ only in checksum bits
+fn only_checksum_differs(first: &str, second: &str, language: bip39::Language) -> bool {
+    match (
+        split_entropy_checksum(first, language),
+        split_entropy_checksum(second, language),
+    ) {
+        (Ok((mut first_entropy, first_checksum)), Ok((mut second_entropy, second_checksum))) => {
+            let same_entropy = first_entropy == second_entropy;
+            first_entropy.zeroize();
+            second_entropy.zeroize();
+            same_entropy && first_checksum != second_checksum
+        }
+        _ => false,
+    }
+}
+
+pub fn handle_diff(
+    mut first: String,
+    mut second: String,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let bip39_language = language.into();
+    let mut first_normalized = normalize_mnemonic(&first, bip39_language);
+    let mut second_normalized = normalize_mnemonic(&second, bip39_language);
+    first.zeroize();
+    second.zeroize();
+
+    let first_words: Vec<&str> = first_normalized.split_whitespace().collect();
+    let second_words: Vec<&str> = second_normalized.split_whitespace().collect();
+    let positions = first_words.len().max(second_words.len());
+
+    let same_style = Style::new().green();
+    let diff_style = Style::new().bold().red();
+    let mut differences = 0;
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Mnemonic Diff")?;
+    out.field(
+        "Words",
+        format_args!("{} vs {}", first_words.len(), second_words.len()),
+    )?;
+    out.blank()?;
+    for position in 0..positions {
+        let left = first_words.get(position).copied().unwrap_or("-");
+        let right = second_words.get(position).copied().unwrap_or("-");
+        let same = first_words.get(position) == second_words.get(position);
+        if !same {
+            differences += 1;
+        }
+        let (mark, style) = if same {
+            ("✓", &same_style)
+        } else {
+            ("✗", &diff_style)
+        };
+        if !quiet {
+            out.secret_line(format_args!(
+                "{:>2}. {}",
+                position + 1,
+                style.apply_to(format!("{left:<10} {right:<10} {mark}"))
+            ))?;
+        }
+    }
+
+    let (first_len, second_len) = (first_words.len(), second_words.len());
+    let checksum_only = differences > 0
+        && first_len == second_len
+        && only_checksum_differs(&first_normalized, &second_normalized, bip39_language);
+    drop(first_words);
+    drop(second_words);
+    first_normalized.zeroize();
+    second_normalized.zeroize();
+
+    if quiet {
+        out.data(if differences == 0 {
+            "identical"
+        } else {
+            "different"
+        })?;
+    } else {
+        out.blank()?;
+        if differences == 0 {
+            out.line("✓ Mnemonics are identical")?;
+        } else if first_len != second_len {
+            out.line(format_args!(
+                "✗ Word counts differ ({first_len} vs {second_len})"
+            ))?;
+        } else if checksum_only {
+            out.line(
+                "✗ Same entropy, only the checksum bits differ (one phrase has an invalid checksum)",
+            )?;
+        } else {
+            out.line(format_args!("✗ {differences} word substitution(s)"))?;
+        }
+    }
+
+    if differences == 0 {
+        Ok(())
+    } else {
+        Err(CliError::MnemonicsDiffer { differences })
+    }
+}
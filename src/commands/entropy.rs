@@ -1,17 +1,173 @@
-use bip39::Mnemonic;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use bip39::{Language, Mnemonic};
+use clap::ValueEnum;
+use sha2::{Digest, Sha256};
 use zeroize::Zeroize;
 
-use crate::cli::LanguageOption;
+use crate::cli::{ByteOrder, GlobalArgs, LanguageOption, PadSide, WordCount, WordLayout};
 use crate::error::CliError;
-use crate::security::{validate_entropy_hex, validate_mnemonic_word_count};
+use crate::memory::SecretBuffer;
+use crate::output::Output;
+use crate::security::{
+    analyze_entropy_quality, check_words, entropy_checksum, entropy_self_test, entropy_to_dice,
+    nibble_counts, normalize_mnemonic, parse_custom_mnemonic, read_input_file,
+    split_entropy_checksum, to_custom_words, validate_entropy_hex, validate_mnemonic_word_count,
+    EntropyQuality, ENTROPY_HEX_LENGTHS,
+};
 
-pub fn handle_from_entropy(
-    entropy: String,
-    language: LanguageOption,
+/// Print the full entropy quality report
+pub fn print_quality_report(out: &mut Output, quality: &EntropyQuality) -> io::Result<()> {
+    out.section("🔬 Entropy Quality Analysis")?;
+    out.field("Score", format_args!("{:.2}/1.0", quality.score))?;
+    out.field(
+        "Shannon entropy",
+        format_args!("{:.2} bits per byte", quality.shannon_entropy),
+    )?;
+
+    if !quality.issues.is_empty() {
+        out.blank()?;
+        out.line("⚠️  Issues detected:")?;
+        for issue in &quality.issues {
+            out.line(format_args!("  • {issue}"))?;
+        }
+    }
+
+    out.blank()?;
+    out.line("💡 Recommendations:")?;
+    for rec in &quality.recommendations {
+        out.line(format_args!("  • {rec}"))?;
+    }
+    out.blank()
+}
+
+/// Validate and decode a hex entropy string
+pub fn decode_entropy_hex(entropy: &str) -> Result<Vec<u8>, CliError> {
+    validate_entropy_hex(entropy)?;
+    Ok(hex::decode(entropy)?)
+}
+
+/// Scores below this get a warning from `from-entropy`
+const ENTROPY_WARNING_SCORE: f64 = 0.8;
+
+/// Warn about low-quality entropy, or refuse it below `min_score`
+fn check_entropy_quality(
+    entropy: &[u8],
+    min_score: Option<f64>,
     quiet: bool,
 ) -> Result<(), CliError> {
-    validate_entropy_hex(&entropy)?;
-    let mut entropy_bytes = hex::decode(&entropy)?;
+    let quality = analyze_entropy_quality(entropy);
+
+    if let Some(threshold) = min_score {
+        if quality.score < threshold {
+            return Err(CliError::WeakEntropy {
+                score: quality.score,
+                threshold,
+            });
+        }
+    }
+
+    if quality.score < ENTROPY_WARNING_SCORE && !quiet {
+        eprintln!(
+            "⚠️  Warning: entropy quality score is {:.2}/1.0",
+            quality.score
+        );
+        for issue in &quality.issues {
+            eprintln!("  • {issue}");
+        }
+        eprintln!();
+    }
+    Ok(())
+}
+
+pub struct FromEntropyOptions {
+    pub entropy: Option<String>,
+    pub input_file: Option<PathBuf>,
+    pub min_entropy_score: Option<f64>,
+    pub byte_order: ByteOrder,
+    pub pad_entropy: Option<PadSide>,
+    pub words_file: Option<PathBuf>,
+    pub all_languages: bool,
+    pub layout: WordLayout,
+    pub language: LanguageOption,
+    pub quiet: bool,
+}
+
+/// Zero-pad `entropy` on `side` up to the next valid length, returning how many
+/// digits were added; empty, valid-length and over-long input is left alone
+fn pad_entropy_hex(entropy: &mut String, side: PadSide) -> usize {
+    let given = entropy.trim();
+    let Some(&target) = ENTROPY_HEX_LENGTHS
+        .iter()
+        .find(|&&len| !given.is_empty() && len >= given.len())
+    else {
+        return 0;
+    };
+    let padding = target - given.len();
+    if padding == 0 {
+        return 0;
+    }
+    let zeros = "0".repeat(padding);
+    let padded = match side {
+        PadSide::Left => format!("{zeros}{given}"),
+        PadSide::Right => format!("{given}{zeros}"),
+    };
+    entropy.zeroize();
+    *entropy = padded;
+    padding
+}
+
+pub fn handle_from_entropy(opts: FromEntropyOptions, global: &GlobalArgs) -> Result<(), CliError> {
+    let FromEntropyOptions {
+        entropy,
+        input_file,
+        min_entropy_score,
+        byte_order,
+        pad_entropy,
+        words_file,
+        all_languages,
+        layout,
+        language,
+        quiet,
+    } = opts;
+    let mut out = Output::stdout(global, quiet);
+    let word_list = words_file
+        .as_deref()
+        .map(super::load_custom_word_list)
+        .transpose()?;
+    let mut entropy = match input_file {
+        Some(path) => read_input_file(&path)?,
+        None => entropy.unwrap_or_default(),
+    };
+    let padding = match pad_entropy {
+        Some(side) => pad_entropy_hex(&mut entropy, side),
+        None => 0,
+    };
+    if padding > 0 {
+        // Always on stderr, even with --quiet: the mnemonic is weaker than its length suggests
+        let side = if pad_entropy == Some(PadSide::Left) {
+            "left"
+        } else {
+            "right"
+        };
+        eprintln!("⚠️  WARNING: --pad-entropy added {padding} zero hex digit(s) on the {side}.");
+        eprintln!(
+            "   Only {} of the {} entropy bits were supplied; padding adds no randomness.",
+            (entropy.len() - padding) * 4,
+            entropy.len() * 4
+        );
+    }
+    let decoded = decode_entropy_hex(&entropy);
+    entropy.zeroize();
+    let mut entropy_bytes = decoded?;
+    if byte_order == ByteOrder::Little {
+        entropy_bytes.reverse();
+    }
+    if let Err(e) = check_entropy_quality(&entropy_bytes, min_entropy_score, quiet) {
+        entropy_bytes.zeroize();
+        return Err(e);
+    }
     let mnemonic = Mnemonic::from_entropy_in(language.into(), &entropy_bytes)?;
 
     let word_count = match entropy_bytes.len() {
@@ -29,42 +185,598 @@ pub fn handle_from_entropy(
             });
         }
     };
-    if !quiet {
-        let bits = entropy_bytes.len() * 8;
-        println!("Mnemonic from Entropy");
-        println!("══════════════════════");
-        println!("Input entropy: {bits} bits ({} bytes)", entropy_bytes.len());
-        println!("Output words: {word_count}");
-        println!("Language: {language:?}");
-        println!();
-    }
-    println!("{mnemonic}");
+    let bits = entropy_bytes.len() * 8;
+    out.section_with_rule("Mnemonic from Entropy", 22)?;
+    out.field(
+        "Input entropy",
+        format_args!("{bits} bits ({} bytes)", entropy_bytes.len()),
+    )?;
+    out.field("Output words", word_count)?;
+    out.field("Byte order", format_args!("{byte_order:?}"))?;
+    if padding > 0 {
+        out.field(
+            "Padding",
+            format_args!("{padding} zero hex digit(s) (--pad-entropy)"),
+        )?;
+    }
+    match &words_file {
+        Some(path) => out.field("Word list", format_args!("custom ({})", path.display()))?,
+        None if all_languages => out.field("Language", "all")?,
+        None => out.field("Language", format_args!("{language:?}"))?,
+    }
+    out.blank()?;
+    if all_languages {
+        let result = print_all_languages(&mut out, &entropy_bytes, layout.redact);
+        entropy_bytes.zeroize();
+        return result;
+    }
+    let mut phrase = match &word_list {
+        Some(words) => to_custom_words(&mnemonic, words),
+        None => mnemonic.to_string(),
+    };
+    let printed = super::emit_mnemonic(&mut out, &phrase, layout, global);
+    phrase.zeroize();
     entropy_bytes.zeroize(); // Clear entropy from memory
 
+    printed
+}
+
+/// Print the mnemonic for `entropy` in every language, labeled by name
+///
+/// Quiet output is `<--language value>\t<phrase>` per line for scripts.
+fn print_all_languages(out: &mut Output, entropy: &[u8], redact: bool) -> Result<(), CliError> {
+    for language in LanguageOption::value_variants() {
+        let mnemonic = Mnemonic::from_entropy_in((*language).into(), entropy)?;
+        let mut phrase = if redact {
+            super::redact_phrase(&mnemonic.to_string())
+        } else {
+            mnemonic.to_string()
+        };
+        let printed = if out.is_quiet() {
+            let value = language
+                .to_possible_value()
+                .map(|value| value.get_name().to_string())
+                .unwrap_or_default();
+            out.secret_line(format_args!("{value}\t{phrase}"))
+        } else {
+            out.secret_line(format_args!(
+                "{:<21} {phrase}",
+                format!("{}:", language.display_name())
+            ))
+        };
+        phrase.zeroize();
+        printed?;
+    }
     Ok(())
 }
 
-pub fn handle_entropy(
-    mnemonic: String,
+/// Parse comma- or space-separated word indices, rejecting values outside 0-2047
+pub fn parse_word_indices(indices: &str) -> Result<Vec<u16>, CliError> {
+    indices
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .enumerate()
+        .map(|(i, token)| {
+            token
+                .parse::<u16>()
+                .ok()
+                .filter(|&index| index < 2048)
+                .ok_or_else(|| CliError::InvalidWordIndex {
+                    value: token.to_string(),
+                    position: Some(i + 1),
+                })
+        })
+        .collect()
+}
+
+/// Parse a metal-backup grid of `<position> <index>` lines, one per word
+///
+/// Positions count from 1 and must appear in order; blank lines and `#`
+/// comments are skipped. Errors point at the grid line or word position.
+pub fn parse_index_grid(grid: &str) -> Result<Vec<u16>, CliError> {
+    let mut indices = Vec::new();
+    for (line_number, line) in grid.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let grid_error = |message: String| CliError::InvalidIndexGrid {
+            line: line_number + 1,
+            message,
+        };
+
+        let fields: Vec<&str> = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty())
+            .collect();
+        let [position, index] = fields[..] else {
+            return Err(grid_error(format!(
+                "expected a position and an index, found {} fields",
+                fields.len()
+            )));
+        };
+
+        let expected = indices.len() + 1;
+        if position.trim_end_matches('.').parse::<usize>().ok() != Some(expected) {
+            return Err(grid_error(format!(
+                "expected position {expected}, found \"{position}\""
+            )));
+        }
+        let index = index
+            .parse::<u16>()
+            .ok()
+            .filter(|&index| index < 2048)
+            .ok_or_else(|| CliError::InvalidWordIndex {
+                value: index.to_string(),
+                position: Some(expected),
+            })?;
+        indices.push(index);
+    }
+    Ok(indices)
+}
+
+pub fn handle_from_indices(
+    indices: Option<String>,
+    grid_file: Option<PathBuf>,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let parsed = match grid_file {
+        Some(path) => {
+            let mut grid = read_input_file(&path)?;
+            let parsed = parse_index_grid(&grid);
+            grid.zeroize();
+            parsed
+        }
+        None => {
+            let mut indices = indices.unwrap_or_default();
+            let parsed = parse_word_indices(&indices);
+            indices.zeroize();
+            parsed
+        }
+    };
+    let mut word_indices = parsed?;
+
+    let word_list = Language::from(language).word_list();
+    let mut phrase = word_indices
+        .iter()
+        .map(|&index| word_list[usize::from(index)])
+        .collect::<Vec<_>>()
+        .join(" ");
+    word_indices.zeroize();
+
+    let result = validate_mnemonic_word_count(&phrase)
+        .and_then(|()| Ok(Mnemonic::parse_in_normalized(language.into(), &phrase)?));
+    if let Err(e) = result {
+        phrase.zeroize();
+        return Err(e);
+    }
+
+    let mut out = Output::stdout(global, quiet);
+    let emitted = print_indices_header(&mut out, &phrase, language)
+        .map_err(CliError::from)
+        .and_then(|()| super::emit_secret(&mut out, &phrase, global));
+    phrase.zeroize();
+    emitted
+}
+
+/// Hashed image material must be at least this many times the entropy it yields
+const IMAGE_MATERIAL_FACTOR: usize = 4;
+
+/// Pack the least-significant bit of every byte, eight samples per output byte
+fn pack_lsbs(samples: &[u8]) -> Vec<u8> {
+    samples
+        .chunks_exact(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0u8, |byte, sample| (byte << 1) | (sample & 1))
+        })
+        .collect()
+}
+
+pub fn handle_from_image(
+    path: &Path,
+    words: WordCount,
+    lsb: bool,
+    min_entropy_score: Option<f64>,
     language: LanguageOption,
+    global: &GlobalArgs,
     quiet: bool,
 ) -> Result<(), CliError> {
+    let file_error = |message: String, hint: &str| CliError::InputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+    let image = image::open(path).map_err(|e| {
+        file_error(
+            e.to_string(),
+            "Use a PNG, JPEG or BMP photo straight from the camera",
+        )
+    })?;
+    let (width, height) = (image.width(), image.height());
+    let mut pixels = image.into_bytes();
+    let mut material = if lsb {
+        let packed = pack_lsbs(&pixels);
+        pixels.zeroize();
+        packed
+    } else {
+        pixels
+    };
+
+    let needed = words.to_entropy_bytes() * IMAGE_MATERIAL_FACTOR;
+    if material.len() < needed {
+        let found = material.len();
+        material.zeroize();
+        return Err(file_error(
+            format!("image yields {found} bytes of material, {needed} needed"),
+            "Use a larger photo, or drop --lsb",
+        ));
+    }
+
+    eprintln!("⚠️  Image entropy is only as unpredictable as the photo itself.");
+    eprintln!("   Use a fresh, unpublished shot with visible sensor noise (e.g. a dark, high-ISO");
+    eprintln!("   frame with the lens covered); a photo anyone else has seen gives no security.");
+    eprintln!();
+    if let Err(e) = check_entropy_quality(&material, min_entropy_score, quiet) {
+        material.zeroize();
+        return Err(e);
+    }
+
+    let mut digest = Sha256::digest(&material);
+    material.zeroize();
+    let mut entropy =
+        SecretBuffer::from_slice(&digest[..words.to_entropy_bytes()], global.memory_lock);
+    digest.zeroize();
+    let mnemonic = Mnemonic::from_entropy_in(language.into(), &entropy);
+    entropy.zeroize();
+    let mut phrase = mnemonic?.to_string();
+
+    let mut out = Output::stdout(global, quiet);
+    let emitted = print_image_header(&mut out, path, (width, height), lsb, words, language)
+        .map_err(CliError::from)
+        .and_then(|()| super::emit_secret(&mut out, &phrase, global));
+    phrase.zeroize();
+    emitted
+}
+
+fn print_image_header(
+    out: &mut Output,
+    path: &Path,
+    (width, height): (u32, u32),
+    lsb: bool,
+    words: WordCount,
+    language: LanguageOption,
+) -> io::Result<()> {
+    out.section("Mnemonic from Image")?;
+    out.field(
+        "Image",
+        format_args!("{} ({width}x{height})", path.display()),
+    )?;
+    out.field(
+        "Hashed",
+        if lsb {
+            "least-significant bit of each sample, SHA-256"
+        } else {
+            "all pixel bytes, SHA-256"
+        },
+    )?;
+    out.field("Words", words.to_word_count())?;
+    out.field("Language", format_args!("{language:?}"))?;
+    out.blank()
+}
+
+fn print_indices_header(
+    out: &mut Output,
+    phrase: &str,
+    language: LanguageOption,
+) -> io::Result<()> {
+    out.section("Mnemonic from Indices")?;
+    out.field("Words", phrase.split_whitespace().count())?;
+    out.field("Language", format_args!("{language:?}"))?;
+    out.blank()
+}
+
+/// Print the word list index of every word, e.g. for grid-based metal backups
+fn print_word_indices(
+    out: &mut Output,
+    mnemonic: &Mnemonic,
+    language: LanguageOption,
+) -> io::Result<()> {
+    if out.is_quiet() {
+        let indices: Vec<String> = mnemonic.word_indices().map(|i| i.to_string()).collect();
+        return out.secret_line(indices.join(" "));
+    }
+
+    out.section("Word Indices")?;
+    out.field("Words", mnemonic.word_count())?;
+    out.field("Language", format_args!("{language:?}"))?;
+    out.blank()?;
+    for (position, (word, index)) in mnemonic.words().zip(mnemonic.word_indices()).enumerate() {
+        out.secret_line(format_args!("{:>2}. {word:<10} {index:>4}", position + 1))?;
+    }
+    Ok(())
+}
+
+/// Print how entropy plus checksum bits partition into the 11-bit word indices
+pub fn print_encoding_table(
+    out: &mut Output,
+    mnemonic: &Mnemonic,
+    entropy: &[u8],
+) -> io::Result<()> {
+    let checksum_bits = entropy.len() / 4;
+    let word_count = mnemonic.word_count();
+
+    out.section("Word Encoding")?;
+    out.field("Entropy bits", entropy.len() * 8)?;
+    out.field(
+        "Checksum bits",
+        format_args!(
+            "{checksum_bits} ({:0checksum_bits$b}, first bits of SHA-256(entropy))",
+            entropy_checksum(entropy)
+        ),
+    )?;
+    out.line(format_args!(
+        "The checksum is the final {checksum_bits} bits of word {word_count}"
+    ))?;
+    out.blank()?;
+
+    for (position, (word, index)) in mnemonic.words().zip(mnemonic.word_indices()).enumerate() {
+        let mut bits = format!("{index:011b}");
+        if position + 1 == word_count {
+            bits.insert(11 - checksum_bits, '|');
+        }
+        out.secret_line(format_args!(
+            "{:>2}. {bits:<12} {index:>4} {word}",
+            position + 1
+        ))?;
+    }
+    Ok(())
+}
+
+/// Print how often each hex digit occurs in the entropy, as a bar chart
+pub fn print_nibble_histogram(out: &mut Output, entropy: &[u8]) -> io::Result<()> {
+    let counts = nibble_counts(entropy);
+    let nibbles = entropy.len() * 2;
+
+    out.section("Nibble Histogram")?;
+    out.field(
+        "Nibbles",
+        format_args!(
+            "{nibbles} (expected {:.1} per digit)",
+            nibbles as f64 / 16.0
+        ),
+    )?;
+    out.blank()?;
+    for (digit, &count) in counts.iter().enumerate() {
+        out.line(format_args!(
+            "{digit:x} {count:>3} {}",
+            "█".repeat(count as usize)
+        ))?;
+    }
+    Ok(())
+}
+
+pub struct EntropyOptions {
+    pub mnemonic: Option<String>,
+    pub input_file: Option<PathBuf>,
+    pub show_indices: bool,
+    pub byte_order: ByteOrder,
+    pub show_checksum: bool,
+    pub redact: bool,
+    pub raw: bool,
+    pub as_dice: bool,
+    pub words_file: Option<PathBuf>,
+    pub language: LanguageOption,
+    pub quiet: bool,
+}
+
+/// Rolls per group in the dice listing, to make copying them down easier
+const DICE_GROUP: usize = 5;
+
+/// Print the entropy as dice rolls, grouped unless quiet
+fn print_dice_rolls(
+    out: &mut Output,
+    entropy: &[u8],
+    word_count: usize,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    let mut rolls = entropy_to_dice(entropy);
+    out.section("Entropy as Dice Rolls")?;
+    out.field("Input words", word_count)?;
+    out.field("Entropy", format_args!("{} bits", entropy.len() * 8))?;
+    out.field(
+        "Rolls",
+        format_args!("{} (base 6, most significant first)", rolls.len()),
+    )?;
+    out.blank()?;
+
+    let mut digits: String = rolls.iter().map(|&roll| char::from(b'0' + roll)).collect();
+    rolls.zeroize();
+    let mut shown = if out.is_quiet() {
+        digits.clone()
+    } else {
+        digits
+            .as_bytes()
+            .chunks(DICE_GROUP)
+            .map(|group| std::str::from_utf8(group).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    digits.zeroize();
+    let emitted = super::emit_secret(out, &shown, global);
+    shown.zeroize();
+    emitted
+}
+
+pub fn handle_entropy(opts: EntropyOptions, global: &GlobalArgs) -> Result<(), CliError> {
+    let EntropyOptions {
+        mnemonic,
+        input_file,
+        show_indices,
+        byte_order,
+        show_checksum,
+        redact,
+        raw,
+        as_dice,
+        words_file,
+        language,
+        quiet,
+    } = opts;
+    let quiet = quiet || raw;
+    let mut out = Output::stdout(global, quiet);
+    let mut raw_mnemonic = match input_file {
+        Some(path) => read_input_file(&path)?,
+        None => mnemonic.unwrap_or_default(),
+    };
+    let mnemonic = normalize_mnemonic(&raw_mnemonic, language.into());
+    raw_mnemonic.zeroize();
     validate_mnemonic_word_count(&mnemonic)?;
-    let mnemonic_obj = Mnemonic::parse_in_normalized(language.into(), &mnemonic)?;
-    let entropy = mnemonic_obj.to_entropy();
+    let mnemonic_obj = match words_file {
+        Some(path) => parse_custom_mnemonic(&mnemonic, &super::load_custom_word_list(&path)?)?,
+        None => {
+            check_words(
+                &mnemonic,
+                language.into(),
+                global.suggestions,
+                global.max_edit_distance,
+            )?;
+            Mnemonic::parse_in_normalized(language.into(), &mnemonic)?
+        }
+    };
+
+    if show_indices {
+        print_word_indices(&mut out, &mnemonic_obj, language)?;
+        return Ok(());
+    }
+    let mut entropy = mnemonic_obj.to_entropy();
+    if as_dice {
+        let printed = print_dice_rolls(
+            &mut out,
+            &entropy,
+            mnemonic.split_whitespace().count(),
+            global,
+        );
+        entropy.zeroize();
+        return printed;
+    }
+    let computed_checksum = entropy_checksum(&entropy);
+    if byte_order == ByteOrder::Little {
+        entropy.reverse();
+    }
 
     let bits = entropy.len() * 8;
-    if !quiet {
-        let word_count = mnemonic.split_whitespace().count();
-        println!("Entropy Extraction");
-        println!("═══════════════════");
-        println!("Input words: {word_count}");
-        println!("Output entropy: {bits} bits ({} bytes)", entropy.len());
-        println!("Language: {language:?}");
-        println!();
-    }
-    let encoded_entropy = hex::encode(entropy);
-    println!("{encoded_entropy}");
+    out.section_with_rule("Entropy Extraction", 19)?;
+    out.field("Input words", mnemonic.split_whitespace().count())?;
+    out.field(
+        "Output entropy",
+        format_args!("{bits} bits ({} bytes)", entropy.len()),
+    )?;
+    out.field("Byte order", format_args!("{byte_order:?}"))?;
+    out.field("Language", format_args!("{language:?}"))?;
+    out.blank()?;
+    if raw {
+        let written = out.raw(&entropy);
+        entropy.zeroize();
+        return Ok(written?);
+    }
+    let mut encoded_entropy = global.hex_case.encode(&entropy);
+    let printed = if redact {
+        out.secret(&super::redact_word(&encoded_entropy))
+            .map_err(CliError::from)
+    } else {
+        super::emit_secret(&mut out, &encoded_entropy, global)
+    };
+    encoded_entropy.zeroize();
+    entropy.zeroize();
+    printed?;
+
+    if show_checksum && !quiet {
+        let (mut parsed_entropy, encoded_checksum) =
+            split_entropy_checksum(&mnemonic, language.into())?;
+        parsed_entropy.zeroize();
+        print_checksum(&mut out, computed_checksum, encoded_checksum, bits / 32)?;
+    }
+
+    Ok(())
+}
+
+/// Print the checksum computed from the entropy next to the one encoded in the last word
+fn print_checksum(
+    out: &mut Output,
+    computed: u8,
+    encoded: u8,
+    checksum_bits: usize,
+) -> io::Result<()> {
+    out.blank()?;
+    out.section("Checksum")?;
+    out.field(
+        "Bits",
+        format_args!("{checksum_bits} (first bits of SHA-256(entropy))"),
+    )?;
+    out.field("Binary", format_args!("{computed:0checksum_bits$b}"))?;
+    out.field("Hex", format_args!("{computed:#04x}"))?;
+    if computed == encoded {
+        out.line("✓ Matches the checksum encoded in the last word")
+    } else {
+        out.line(format_args!(
+            "✗ Last word encodes {encoded:0checksum_bits$b}"
+        ))
+    }
+}
 
+pub fn handle_analyze_entropy(
+    entropy: String,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut entropy_bytes = decode_entropy_hex(&entropy)?;
+    let quality = analyze_entropy_quality(&entropy_bytes);
+    entropy_bytes.zeroize(); // Clear entropy from memory
+
+    let mut out = Output::stdout(global, quiet);
+    if quiet {
+        out.data(format_args!("{:.2}", quality.score))?;
+    } else {
+        print_quality_report(&mut out, &quality)?;
+    }
+
+    Ok(())
+}
+
+/// Minimum mean score expected from OsRng samples
+const SELF_TEST_MIN_MEAN: f64 = 0.9;
+
+pub fn handle_self_test(
+    samples: usize,
+    size: usize,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let report = entropy_self_test(samples, size);
+
+    let mut out = Output::stdout(global, quiet);
+    if quiet {
+        out.data(format_args!("{:.3}", report.mean))?;
+    } else {
+        out.section("Entropy Quality Self-Test")?;
+        out.field(
+            "Samples",
+            format_args!("{} x {size} bytes (OsRng)", report.samples),
+        )?;
+        out.field("Mean score", format_args!("{:.3}", report.mean))?;
+        out.field("Min score", format_args!("{:.3}", report.min))?;
+        out.field("Max score", format_args!("{:.3}", report.max))?;
+        out.field("Scored below 0.8", report.flagged)?;
+    }
+
+    if report.mean < SELF_TEST_MIN_MEAN {
+        return Err(CliError::SelfTestFailed {
+            mean: report.mean,
+            threshold: SELF_TEST_MIN_MEAN,
+        });
+    }
     Ok(())
 }
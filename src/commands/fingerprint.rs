@@ -0,0 +1,47 @@
+use bip39::Mnemonic;
+use bitcoin::bip32::{Fingerprint, Xpriv};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::Network;
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageOption};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{normalize_mnemonic, validate_mnemonic_word_count};
+
+/// Fingerprint of the BIP32 master key derived from a seed
+pub fn master_fingerprint(seed: &[u8]) -> Result<Fingerprint, CliError> {
+    let secp = Secp256k1::new();
+    let mut master = Xpriv::new_master(Network::Bitcoin, seed)?;
+    let fingerprint = master.fingerprint(&secp);
+    master.private_key.non_secure_erase(); // Clear master key from memory
+    Ok(fingerprint)
+}
+
+pub fn handle_fingerprint(
+    mnemonic: String,
+    mut passphrase: String,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut mnemonic = normalize_mnemonic(&mnemonic, language.into());
+    validate_mnemonic_word_count(&mnemonic)?;
+    let mnemonic_obj = Mnemonic::parse_in_normalized(language.into(), &mnemonic)?;
+    mnemonic.zeroize();
+
+    let mut seed = mnemonic_obj.to_seed(&passphrase);
+    let passphrase_used = !passphrase.is_empty();
+    passphrase.zeroize();
+    let fingerprint = master_fingerprint(&seed);
+    seed.zeroize(); // Clear seed from memory
+    let fingerprint = fingerprint?;
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Master Key Fingerprint")?;
+    out.field("Passphrase", if passphrase_used { "Used" } else { "None" })?;
+    out.blank()?;
+    out.data(global.hex_case.encode(fingerprint.to_bytes()))?;
+
+    Ok(())
+}
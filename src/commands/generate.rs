@@ -1,167 +1,794 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use bip39::Mnemonic;
 use rand::{rngs::OsRng, RngCore};
-use zeroize::Zeroize;
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
 
-use crate::cli::{LanguageOption, WordCount};
+use crate::cli::{
+    EntropySource, GlobalArgs, HexCase, LanguageOption, SeedFormat, WordCount, WordLayout,
+};
+use crate::encryption::{encrypt_seed, read_key_file};
 use crate::error::CliError;
+use crate::memory::{SecretBuffer, SecretString};
+use crate::output::Output;
+use crate::security::{
+    find_invalid_words, mnemonic_commitment, normalize_mnemonic, read_input_file,
+};
 
 pub struct GenerateOptions {
-    pub words: WordCount,
+    pub words: Option<WordCount>,
+    pub entropy_source: EntropySource,
+    pub deterministic: Option<String>,
+    pub extra_entropy: Option<String>,
     pub language: LanguageOption,
+    pub avoid_words: Vec<String>,
+    pub no_repeats: bool,
     pub show_entropy: bool,
+    pub verbose_encoding: bool,
+    pub histogram: bool,
+    pub raw: bool,
     pub show_seed: bool,
+    pub seed_only: bool,
+    pub seed_format: SeedFormat,
+    pub encrypt_to: Option<PathBuf>,
+    pub copy: bool,
+    pub out_fd: Option<i32>,
+    pub clipboard_timeout: Duration,
     pub passphrase: String,
+    pub passphrase_env: Option<String>,
+    pub passphrase_file: Option<PathBuf>,
     pub secure_passphrase: bool,
+    pub passphrase_confirm: bool,
     pub analyze_entropy: bool,
+    pub min_passphrase_score: Option<f64>,
+    pub dry_run: bool,
+    pub explain: bool,
+    pub hash_output: bool,
+    pub hash_salt: Option<String>,
+    pub count: Option<usize>,
+    pub unique: bool,
+    pub layout: WordLayout,
     pub quiet: bool,
 }
 
-pub fn handle_generate(opts: GenerateOptions) -> Result<(), CliError> {
-    let mut entropy = vec![0u8; opts.words.to_entropy_bytes()];
-    OsRng.fill_bytes(&mut entropy);
+/// Produce entropy bytes from the selected `--entropy-source`
+///
+/// OsRng fills the (optionally locked) buffer directly; hex and file entropy is
+/// decoded first and then copied in.
+fn read_entropy(
+    source: &EntropySource,
+    words: Option<WordCount>,
+    lock: bool,
+) -> Result<SecretBuffer, CliError> {
+    let mut entropy_hex = match source {
+        EntropySource::Os => {
+            let mut entropy =
+                SecretBuffer::new(words.unwrap_or(WordCount::Twelve).to_entropy_bytes(), lock);
+            OsRng.fill_bytes(&mut entropy);
+            return Ok(entropy);
+        }
+        EntropySource::Hex(hex) => hex.clone(),
+        EntropySource::File(path) => read_input_file(path)?,
+    };
+    let decoded = super::entropy::decode_entropy_hex(&entropy_hex);
+    entropy_hex.zeroize();
+    let mut entropy = decoded?;
+
+    if let Some(words) = words {
+        if entropy.len() != words.to_entropy_bytes() {
+            let actual = entropy.len() * 2;
+            entropy.zeroize();
+            return Err(CliError::InvalidEntropyLength {
+                actual,
+                expected: vec![words.to_entropy_bytes() * 2],
+                hint: format!(
+                    "--words {} needs {} hex characters; omit --words to infer it from the entropy",
+                    words.to_word_count(),
+                    words.to_entropy_bytes() * 2
+                ),
+            });
+        }
+    }
+
+    let buffer = SecretBuffer::from_slice(&entropy, lock);
+    entropy.zeroize();
+    Ok(buffer)
+}
+
+/// XOR `--extra-entropy` into freshly drawn entropy of the same length
+fn mix_extra_entropy(entropy: &mut [u8], extra: Option<&[u8]>) -> Result<(), CliError> {
+    let Some(extra) = extra else {
+        return Ok(());
+    };
+    if extra.len() != entropy.len() {
+        return Err(extra_entropy_length_error(extra.len(), entropy.len()));
+    }
+    for (byte, mask) in entropy.iter_mut().zip(extra) {
+        *byte ^= mask;
+    }
+    Ok(())
+}
+
+fn extra_entropy_length_error(actual: usize, expected: usize) -> CliError {
+    CliError::InvalidEntropyLength {
+        actual: actual * 2,
+        expected: vec![expected * 2],
+        hint: format!(
+            "--extra-entropy must be exactly as long as the generated entropy ({} hex characters)",
+            expected * 2
+        ),
+    }
+}
+
+/// Testing-only entropy: SHA-256 of `seed`, truncated to the word count's size
+fn deterministic_entropy(seed: &str, words: Option<WordCount>, lock: bool) -> SecretBuffer {
+    let mut digest = Sha256::digest(seed.as_bytes());
+    let entropy = SecretBuffer::from_slice(
+        &digest[..words.unwrap_or(WordCount::Twelve).to_entropy_bytes()],
+        lock,
+    );
+    digest.zeroize();
+    entropy
+}
+
+/// Bytes of random salt drawn for `--hash-output` when `--hash-salt` is not given
+const COMMITMENT_SALT_BYTES: usize = 32;
+
+/// Decode `--hash-salt`, or draw a fresh random salt
+fn commitment_salt(hash_salt: Option<&str>) -> Result<Vec<u8>, CliError> {
+    match hash_salt {
+        Some(hex_salt) => Ok(hex::decode(hex_salt.trim())?),
+        None => {
+            let mut salt = vec![0u8; COMMITMENT_SALT_BYTES];
+            OsRng.fill_bytes(&mut salt);
+            Ok(salt)
+        }
+    }
+}
+
+/// Print the `--hash-output` commitment: salt and HMAC, tab-separated when quiet
+fn print_commitment(
+    out: &mut Output,
+    salt: &[u8],
+    phrase: &str,
+    hex_case: HexCase,
+) -> io::Result<()> {
+    let commitment = hex_case.encode(mnemonic_commitment(salt, phrase));
+    let salt = hex_case.encode(salt);
+    if out.is_quiet() {
+        return out.data(format_args!("{salt}\t{commitment}"));
+    }
+    out.field("Commitment salt", &salt)?;
+    out.field("Commitment (HMAC-SHA256)", &commitment)?;
+    out.blank()?;
+    out.line("💡 Keep the salt; with it and the mnemonic, the commitment can be recomputed later.")
+}
+
+/// `--analyze-entropy` refuses entropy scoring below this, which only broken entropy does
+const COMPROMISED_ENTROPY_SCORE: f64 = 0.1;
+
+/// How many times `--avoid-word` or `--no-repeats` may redraw entropy before giving up
+const MAX_AVOID_ATTEMPTS: usize = 1000;
+
+/// Normalize `--avoid-word` values, rejecting words that are not in the word list
+fn normalize_avoid_words(
+    words: &[String],
+    language: LanguageOption,
+    global: &GlobalArgs,
+) -> Result<Vec<String>, CliError> {
+    let joined = normalize_mnemonic(&words.join(" "), language.into());
+    // Positions refer to the order of the --avoid-word flags
+    if let Some((position, word, suggestions)) = find_invalid_words(
+        &joined,
+        language.into(),
+        global.suggestions,
+        global.max_edit_distance,
+    )
+    .into_iter()
+    .next()
+    {
+        return Err(CliError::InvalidWord {
+            word,
+            position,
+            suggestions,
+        });
+    }
+    Ok(joined.split_whitespace().map(str::to_string).collect())
+}
+
+/// Whether the mnemonic for `entropy` uses any of the `avoid` words, or repeats
+/// a word when `no_repeats` is set
+fn is_rejected(
+    entropy: &[u8],
+    language: LanguageOption,
+    avoid: &[String],
+    no_repeats: bool,
+) -> Result<bool, CliError> {
+    let mnemonic = Mnemonic::from_entropy_in(language.into(), entropy)?;
+    let found = mnemonic.words().any(|word| avoid.iter().any(|a| a == word));
+    let repeated = no_repeats && {
+        let mut seen = HashSet::new();
+        !mnemonic.words().all(|word| seen.insert(word))
+    };
+    Ok(found || repeated)
+}
+
+/// Draw entropy for one mnemonic from `--entropy-source` (or `--deterministic`),
+/// mix in `--extra-entropy`, and redraw until it passes `--avoid-word` and `--no-repeats`
+fn draw_entropy(
+    opts: &mut GenerateOptions,
+    extra: Option<&[u8]>,
+    avoid: &[String],
+    global: &GlobalArgs,
+) -> Result<SecretBuffer, CliError> {
+    let mut entropy = match opts.deterministic.as_mut() {
+        Some(seed) => {
+            // Always on stderr, even with --quiet: this output must never hold funds
+            eprintln!("⚠️  WARNING: --deterministic output is NOT secure and is for testing only.");
+            eprintln!(
+                "   The mnemonic is derived from a known string; never use it for real funds."
+            );
+            let entropy = deterministic_entropy(seed, opts.words, global.memory_lock);
+            seed.zeroize();
+            entropy
+        }
+        None => read_entropy(&opts.entropy_source, opts.words, global.memory_lock)?,
+    };
+    mix_extra_entropy(&mut entropy, extra)?;
+
+    // Rejection sampling: each redraw is fresh OsRng entropy, so the result is
+    // uniform over the mnemonics that avoid the excluded words (and repeats)
+    let mut attempts = 1;
+    while (!avoid.is_empty() || opts.no_repeats)
+        && is_rejected(&entropy, opts.language, avoid, opts.no_repeats)?
+    {
+        entropy.zeroize();
+        if attempts == MAX_AVOID_ATTEMPTS {
+            return Err(CliError::AvoidWordsExhausted {
+                attempts,
+                excluded: avoid.len(),
+                distinct: opts.no_repeats,
+            });
+        }
+        entropy = read_entropy(&opts.entropy_source, opts.words, global.memory_lock)?;
+        mix_extra_entropy(&mut entropy, extra)?;
+        attempts += 1;
+    }
+    Ok(entropy)
+}
+
+pub fn handle_generate(mut opts: GenerateOptions, global: &GlobalArgs) -> Result<(), CliError> {
+    // Only the seed is encrypted, so the mnemonic must not be printed beside it
+    if opts.encrypt_to.is_some() && !opts.seed_only {
+        return Err(CliError::UsageError {
+            message: "--encrypt-to encrypts only the seed, so it needs --seed-only".to_string(),
+            hint: "Add --seed-only, or drop --encrypt-to".to_string(),
+        });
+    }
+    super::check_encrypt_to(opts.encrypt_to.as_deref(), global)?;
+    // The seed is then the only output, with no headers around it
+    if opts.seed_only {
+        opts.show_seed = true;
+        opts.quiet = true;
+    }
+    // Likewise the raw entropy bytes, which headers would corrupt
+    if opts.raw {
+        opts.quiet = true;
+    }
+    // The walkthrough ends with the seed and prints everything itself
+    if opts.explain {
+        opts.show_seed = true;
+    }
+    let mut out = Output::stdout(global, opts.quiet);
+    // Fail before generating anything if there is no clipboard to copy to
+    let mut clipboard = if opts.copy {
+        Some(crate::clipboard::open_clipboard()?)
+    } else {
+        None
+    };
+    let avoid = normalize_avoid_words(&opts.avoid_words, opts.language, global)?;
+    let salt = if opts.hash_output {
+        Some(commitment_salt(opts.hash_salt.as_deref())?)
+    } else {
+        None
+    };
+    let key = opts.encrypt_to.as_deref().map(read_key_file).transpose()?;
+    let extra = match opts.extra_entropy.as_mut() {
+        Some(hex) => {
+            let decoded = super::entropy::decode_entropy_hex(hex);
+            hex.zeroize();
+            let mut decoded = decoded?;
+            let buffer = SecretBuffer::from_slice(&decoded, global.memory_lock);
+            decoded.zeroize();
+            Some(buffer)
+        }
+        None => None,
+    };
+    if opts.dry_run {
+        let reported = print_dry_run(&opts, extra.as_deref(), global);
+        opts.passphrase.zeroize();
+        if let Some(seed) = opts.deterministic.as_mut() {
+            seed.zeroize();
+        }
+        if let EntropySource::Hex(hex) = &mut opts.entropy_source {
+            hex.zeroize();
+        }
+        return reported;
+    }
+    if let Some(count) = opts.count {
+        return generate_batch(&mut out, &mut opts, count, extra.as_deref(), &avoid, global);
+    }
+    let mut entropy = draw_entropy(&mut opts, extra.as_deref(), &avoid, global)?;
+    if let EntropySource::Hex(hex) = &mut opts.entropy_source {
+        hex.zeroize();
+    }
+    let bits = entropy.len() * 8;
 
     // Analyze entropy quality if requested
     if opts.analyze_entropy {
         let quality = crate::security::analyze_entropy_quality(&entropy);
 
-        if !opts.quiet {
-            println!("🔬 Entropy Quality Analysis");
-            println!("═══════════════════════════");
-            println!("Score: {:.2}/1.0", quality.score);
-
-            if !quality.issues.is_empty() {
-                println!("\n⚠️  Issues detected:");
-                for issue in &quality.issues {
-                    println!("  • {issue}");
-                }
-            }
-
-            println!("\n💡 Recommendations:");
-            for rec in &quality.recommendations {
-                println!("  • {rec}");
-            }
-            println!();
+        if let Err(e) = super::entropy::print_quality_report(&mut out, &quality) {
+            entropy.zeroize();
+            return Err(e.into());
         }
 
         // Only fail if entropy is obviously broken (not just statistically unusual)
-        if quality.score < 0.1 {
+        if quality.score < COMPROMISED_ENTROPY_SCORE {
             entropy.zeroize();
-            return Err(CliError::InvalidHexString {
-                message: "Entropy appears to be severely compromised".to_string(),
-                position: None,
-                hint: "System RNG may be broken. Consider restarting or using hardware RNG."
-                    .to_string(),
+            return Err(CliError::WeakEntropy {
+                score: quality.score,
+                threshold: COMPROMISED_ENTROPY_SCORE,
             });
         }
     }
 
-    // Always use secure entropy source, show confirmation unless quiet
-    if !opts.quiet {
-        println!("✅ Using cryptographically secure entropy source (OsRng)");
+    let source_line = match &opts.entropy_source {
+        _ if opts.deterministic.is_some() => {
+            out.line("⚠️  Using deterministic test entropy (SHA-256 of a string)")
+        }
+        EntropySource::Os => out.line("✅ Using cryptographically secure entropy source (OsRng)"),
+        EntropySource::Hex(_) => out.line("⚠️  Using caller-supplied entropy (hex)"),
+        EntropySource::File(path) => out.line(format_args!(
+            "⚠️  Using caller-supplied entropy (file: {})",
+            path.display()
+        )),
+    };
+    if let Err(e) = source_line {
+        entropy.zeroize();
+        return Err(e.into());
     }
 
-    let mnemonic = Mnemonic::from_entropy_in(opts.language.into(), &entropy)?;
+    let mnemonic = super::profile(global, "encoding", || {
+        Mnemonic::from_entropy_in(opts.language.into(), &entropy)
+    })?;
 
-    let word_count = opts.words.to_word_count();
-    if !opts.quiet {
-        let bits = opts.words.to_entropy_bits();
-        println!("Generated Mnemonic");
-        println!("═══════════════════");
-        println!("Words: {word_count}");
-        println!("Entropy: {bits} bits");
-        println!();
+    let word_count = bits / 32 * 3;
+    if !opts.explain {
+        if let Err(e) = print_generated_header(&mut out, word_count, bits) {
+            entropy.zeroize();
+            return Err(e.into());
+        }
+    }
+    // Copied last, since the copy blocks until the clipboard is cleared
+    let copied = opts.copy.then(|| Zeroizing::new(mnemonic.to_string()));
+    if !opts.seed_only && !opts.copy && !opts.explain {
+        let mut phrase = mnemonic.to_string();
+        let emitted = match opts.out_fd {
+            Some(fd) => crate::security::write_secret_fd(fd, &phrase)
+                .and_then(|()| Ok(out.field("Written to", format_args!("fd {fd}"))?)),
+            // Raw entropy owns stdout, so the mnemonic only goes to a file
+            None if opts.raw && global.output_file.is_none() => Ok(()),
+            // So does the commitment, which is the point of --hash-output
+            None if opts.hash_output && global.output_file.is_none() => {
+                if global.require_tty && !console::Term::stderr().is_term() {
+                    Err(CliError::SecretRefused)
+                } else {
+                    eprintln!("⚠️  WARNING: no --output-file, --out-fd or --copy given; writing the mnemonic to stderr");
+                    eprintln!("{phrase}");
+                    Ok(())
+                }
+            }
+            None => super::emit_mnemonic(&mut out, &phrase, opts.layout, global),
+        };
+        phrase.zeroize();
+        if let Err(e) = emitted {
+            entropy.zeroize();
+            return Err(e);
+        }
+    }
+    if let Some(salt) = &salt {
+        let mut phrase = mnemonic.to_string();
+        let printed = print_commitment(&mut out, salt, &phrase, global.hex_case);
+        phrase.zeroize();
+        if let Err(e) = printed {
+            entropy.zeroize();
+            return Err(e.into());
+        }
     }
-    println!("{mnemonic}");
 
-    if opts.show_entropy {
-        let bits = opts.words.to_entropy_bits();
-        println!();
-        if !opts.quiet {
-            println!("Raw Entropy");
-            println!("═══════════");
-            println!("Bits: {bits}");
-            println!("Bytes: {}", entropy.len());
-            println!();
+    if opts.raw {
+        let written = out.raw(&entropy);
+        entropy.zeroize();
+        return Ok(written?);
+    }
+    if opts.show_entropy && !opts.explain {
+        if let Err(e) = print_entropy_details(&mut out, &mnemonic, &entropy, &opts, global.hex_case)
+        {
+            entropy.zeroize();
+            return Err(e.into());
         }
-        let encoded = hex::encode(&entropy);
-        println!("{encoded}");
     }
 
     if opts.show_seed {
-        // Handle secure passphrase input
+        // Passphrase precedence: --secure-passphrase > --passphrase-env > --passphrase-file > --passphrase
         let final_passphrase = if opts.secure_passphrase {
-            let mut secure_pass = crate::security::secure_input(
-                "Enter passphrase for seed derivation:",
-            )
-            .map_err(|e| CliError::InvalidHexString {
-                message: format!("Failed to read secure passphrase: {e}"),
-                position: None,
-                hint: "Ensure terminal supports secure input".to_string(),
-            })?;
+            let secure_pass = SecretString::from_string(
+                super::passphrase::prompt_passphrase(opts.passphrase_confirm, global)?,
+                global.memory_lock,
+            );
 
             // Always assess passphrase strength
             if !opts.quiet {
                 let strength = crate::security::assess_passphrase_strength(&secure_pass);
 
-                if !opts.quiet {
-                    println!("\n🔐 Passphrase Strength Analysis");
-                    println!("═══════════════════════════════");
-                    println!("Score: {:.2}/1.0", strength.score);
-                    println!("Entropy: {:.1} bits", strength.entropy);
-
-                    if !strength.issues.is_empty() {
-                        println!("\n⚠️  Issues:");
-                        for issue in &strength.issues {
-                            println!("  • {issue}");
-                        }
-                    }
+                out.blank()?;
+                super::passphrase::print_strength_report(&mut out, &strength)?;
 
-                    println!("\n💡 Recommendations:");
-                    for rec in &strength.recommendations {
-                        println!("  • {rec}");
-                    }
-                    println!();
-                }
-
-                if strength.score < 0.6 {
-                    secure_pass.zeroize();
-                    return Err(CliError::InvalidHexString {
-                        message: "Passphrase strength too low".to_string(),
-                        position: None,
-                        hint: "Use a longer, more complex passphrase".to_string(),
-                    });
-                }
+                super::passphrase::enforce_min_score(&strength, opts.min_passphrase_score)?;
             }
 
             secure_pass
         } else {
-            opts.passphrase
+            let resolved = super::passphrase::resolve_passphrase(
+                opts.passphrase,
+                opts.passphrase_env.as_deref(),
+                opts.passphrase_file.as_deref(),
+            );
+            if resolved.is_err() {
+                entropy.zeroize();
+            }
+            SecretString::from_string(resolved?, global.memory_lock)
         };
 
-        let mut seed = mnemonic.to_seed(&final_passphrase);
-        if !opts.quiet {
-            if !opts.show_entropy {
-                println!();
-            }
-            println!("Derived Seed");
-            println!("════════════");
-            println!("Length: 512 bits (64 bytes)");
-            if final_passphrase.is_empty() {
-                println!("Passphrase: None");
-            } else {
-                println!("Passphrase: Used");
-            }
-            println!();
-        } else if opts.show_entropy {
-            println!();
-        }
-        let encoded_seed = hex::encode(seed);
-        println!("{encoded_seed}");
+        let mut derived = super::profile(global, "to_seed (PBKDF2)", || {
+            mnemonic.to_seed(&*final_passphrase)
+        });
+        let mut seed = SecretBuffer::from_slice(&derived, global.memory_lock);
+        derived.zeroize();
+        let passphrase_used = !final_passphrase.is_empty();
+        drop(final_passphrase);
+        let mut encoded_seed = match &key {
+            Some(key) => encrypt_seed(&seed, key),
+            None => super::seed::encode_seed(&seed, opts.seed_format, global.hex_case),
+        };
+        let emitted = if opts.explain {
+            print_explanation(
+                &mut out,
+                &entropy,
+                &mnemonic,
+                &encoded_seed,
+                passphrase_used,
+                global.hex_case,
+            )
+            .map_err(CliError::from)
+        } else {
+            print_seed_header(&mut out, passphrase_used, opts.show_entropy)
+                .map_err(CliError::from)
+                .and_then(|()| {
+                    if opts.seed_only {
+                        super::emit_secret(&mut out, &encoded_seed, global)
+                    } else {
+                        Ok(out.secret_line(&encoded_seed)?)
+                    }
+                })
+        };
+        encoded_seed.zeroize();
         seed.zeroize(); // Clear seed from memory
+        if let Err(e) = emitted {
+            entropy.zeroize();
+            return Err(e);
+        }
     }
 
     // Clear entropy from memory
     entropy.zeroize();
 
+    if let (Some(clipboard), Some(phrase)) = (&mut clipboard, copied) {
+        crate::clipboard::copy_with_auto_clear(clipboard, &phrase, opts.clipboard_timeout)?;
+    }
+
     Ok(())
 }
+
+/// Word count that `--entropy-source` would produce, checking caller-supplied entropy
+///
+/// OsRng is not drawn from; hex and file entropy is read to validate and measure it.
+fn planned_word_count(opts: &GenerateOptions, lock: bool) -> Result<usize, CliError> {
+    if let EntropySource::Os = opts.entropy_source {
+        return Ok(opts.words.unwrap_or(WordCount::Twelve).to_word_count());
+    }
+    let bytes = read_entropy(&opts.entropy_source, opts.words, lock)?.len();
+    if !matches!(bytes, 16 | 20 | 24 | 28 | 32) {
+        return Err(CliError::InvalidEntropyLength {
+            actual: bytes * 2,
+            expected: vec![32, 40, 48, 56, 64],
+            hint: "Check the entropy length and ensure it matches a valid word count".to_string(),
+        });
+    }
+    Ok(bytes / 4 * 3)
+}
+
+/// How many times `--unique` may draw a mnemonic that repeats an earlier one; even
+/// one repeat is astronomically unlikely, so hitting this means the RNG is broken
+const MAX_UNIQUE_DRAWS: usize = 3;
+
+/// Generate `--count` mnemonics, redrawing any that repeat an earlier one with `--unique`
+///
+/// Repeats are detected by the SHA-256 of each mnemonic's entropy, so the set
+/// never holds the phrases themselves.
+fn generate_batch(
+    out: &mut Output,
+    opts: &mut GenerateOptions,
+    count: usize,
+    extra: Option<&[u8]>,
+    avoid: &[String],
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    let mut seen = HashSet::new();
+    let mut phrases = Zeroizing::new(Vec::with_capacity(count));
+    for _ in 0..count {
+        let mut entropy = draw_entropy(opts, extra, avoid, global)?;
+        let mut draws = 1;
+        while opts.unique && !seen.insert(<[u8; 32]>::from(Sha256::digest(&*entropy))) {
+            entropy.zeroize();
+            if draws == MAX_UNIQUE_DRAWS {
+                return Err(CliError::RepeatedEntropy { draws });
+            }
+            entropy = draw_entropy(opts, extra, avoid, global)?;
+            draws += 1;
+        }
+        let mnemonic = Mnemonic::from_entropy_in(opts.language.into(), &entropy);
+        entropy.zeroize();
+        phrases.push(mnemonic?.to_string());
+    }
+
+    out.line("✅ Using cryptographically secure entropy source (OsRng)")?;
+    out.section_with_rule("Generated Mnemonics", 20)?;
+    out.field(
+        "Words",
+        opts.words.unwrap_or(WordCount::Twelve).to_word_count(),
+    )?;
+    out.field("Count", count)?;
+    if opts.unique {
+        out.field("Repeats", "redrawn (--unique)")?;
+    }
+    out.blank()?;
+    if global.output_file.is_some() {
+        let joined = Zeroizing::new(phrases.join("\n"));
+        return super::emit_secret(out, &joined, global);
+    }
+    let width = count.to_string().len();
+    for (index, phrase) in phrases.iter().enumerate() {
+        if out.is_quiet() {
+            out.secret(phrase)?;
+        } else {
+            out.secret_line(format_args!("{:>width$}. {phrase}", index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+/// Report what `generate` would do with these options, for `--dry-run`
+fn print_dry_run(
+    opts: &GenerateOptions,
+    extra: Option<&[u8]>,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    let word_count = planned_word_count(opts, global.memory_lock)?;
+    let entropy_bytes = word_count / 3 * 4;
+    if let Some(extra) = extra {
+        if extra.len() != entropy_bytes {
+            return Err(extra_entropy_length_error(extra.len(), entropy_bytes));
+        }
+    }
+    let passphrase = if !opts.show_seed {
+        None
+    } else if opts.secure_passphrase && opts.passphrase_confirm {
+        Some("prompt, typed twice (--secure-passphrase --passphrase-confirm)".to_string())
+    } else if opts.secure_passphrase {
+        Some("prompt (--secure-passphrase)".to_string())
+    } else {
+        // Reading the variable or file checks that it exists; the value is discarded
+        let mut resolved = super::passphrase::resolve_passphrase(
+            String::new(),
+            opts.passphrase_env.as_deref(),
+            opts.passphrase_file.as_deref(),
+        )?;
+        let given = !resolved.is_empty() || !opts.passphrase.is_empty();
+        resolved.zeroize();
+        Some(match (&opts.passphrase_env, &opts.passphrase_file) {
+            (Some(var), _) => format!("environment variable {var}"),
+            (None, Some(path)) => format!("file {}", path.display()),
+            (None, None) if given => "command line".to_string(),
+            (None, None) => "none".to_string(),
+        })
+    };
+    let source = match &opts.entropy_source {
+        _ if opts.deterministic.is_some() => "deterministic test string".to_string(),
+        EntropySource::Os => "OsRng (not drawn)".to_string(),
+        EntropySource::Hex(_) => "caller-supplied hex".to_string(),
+        EntropySource::File(path) => format!("file {}", path.display()),
+    };
+    let mnemonic = match (opts.out_fd, &global.output_file) {
+        _ if opts.seed_only => "not shown (--seed-only)".to_string(),
+        _ if opts.copy => format!(
+            "clipboard, cleared after {}s",
+            opts.clipboard_timeout.as_secs()
+        ),
+        (Some(fd), _) => format!("fd {fd}"),
+        (None, Some(path)) => format!("file {}", path.display()),
+        (None, None) if opts.raw => "not shown (--raw)".to_string(),
+        (None, None) if opts.hash_output => "stderr (--hash-output)".to_string(),
+        (None, None) => "stdout".to_string(),
+    };
+
+    // The report is the whole output, so it is printed even with --quiet
+    let mut out = Output::stdout(global, false);
+    out.section("Dry Run")?;
+    out.field("Words", word_count)?;
+    out.field("Language", format_args!("{:?}", opts.language))?;
+    out.field("Entropy source", source)?;
+    if opts.no_repeats {
+        out.field("Repeated words", "redrawn (--no-repeats)")?;
+    }
+    if let Some(extra) = extra {
+        out.field(
+            "Extra entropy",
+            format_args!("{} bytes, XORed in", extra.len()),
+        )?;
+    }
+    out.field("Mnemonic", mnemonic)?;
+    if let Some(count) = opts.count {
+        out.field(
+            "Count",
+            if opts.unique {
+                format!("{count}, repeats redrawn (--unique)")
+            } else {
+                count.to_string()
+            },
+        )?;
+    }
+    if let Some(path) = &opts.encrypt_to {
+        out.field(
+            "Seed encryption",
+            format_args!("ChaCha20-Poly1305, key file {}", path.display()),
+        )?;
+    }
+    if opts.hash_output {
+        out.field(
+            "Commitment",
+            if opts.hash_salt.is_some() {
+                "HMAC-SHA256 with the given salt"
+            } else {
+                "HMAC-SHA256 with a random 32-byte salt"
+            },
+        )?;
+    }
+    out.field(
+        "Show entropy",
+        match (opts.show_entropy, opts.raw) {
+            (true, true) => "yes (raw bytes)",
+            (true, false) => "yes",
+            (false, _) => "no",
+        },
+    )?;
+    out.field("Show seed", if opts.show_seed { "yes" } else { "no" })?;
+    if let Some(passphrase) = passphrase {
+        out.field("Passphrase", passphrase)?;
+    }
+    out.field(
+        "Entropy analysis",
+        if opts.analyze_entropy { "yes" } else { "no" },
+    )?;
+    out.blank()?;
+    out.line("✓ Options are valid; no entropy was drawn and nothing secret was printed")?;
+    Ok(())
+}
+
+fn print_generated_header(out: &mut Output, word_count: usize, bits: usize) -> io::Result<()> {
+    out.section_with_rule("Generated Mnemonic", 19)?;
+    out.field("Words", word_count)?;
+    out.field("Entropy", format_args!("{bits} bits"))?;
+    out.blank()
+}
+
+/// The `--show-entropy` hex, which is printed even with `--quiet`, and its extras
+fn print_entropy_details(
+    out: &mut Output,
+    mnemonic: &Mnemonic,
+    entropy: &[u8],
+    opts: &GenerateOptions,
+    hex_case: HexCase,
+) -> io::Result<()> {
+    out.data("")?;
+    out.section("Raw Entropy")?;
+    out.field("Bits", entropy.len() * 8)?;
+    out.field("Bytes", entropy.len())?;
+    out.blank()?;
+    out.secret_line(hex_case.encode(entropy))?;
+
+    if opts.verbose_encoding {
+        out.data("")?;
+        super::entropy::print_encoding_table(out, mnemonic, entropy)?;
+    }
+    if opts.histogram && !out.is_quiet() {
+        out.blank()?;
+        super::entropy::print_nibble_histogram(out, entropy)?;
+    }
+    Ok(())
+}
+
+/// Entropy, its word encoding and the seed in order, each labeled with its role, for `--explain`
+fn print_explanation(
+    out: &mut Output,
+    entropy: &[u8],
+    mnemonic: &Mnemonic,
+    encoded_seed: &str,
+    passphrase_used: bool,
+    hex_case: HexCase,
+) -> io::Result<()> {
+    let bits = entropy.len() * 8;
+    out.section("Entropy → Mnemonic → Seed")?;
+    out.blank()?;
+    out.line(format_args!(
+        "1. Entropy: the random input ({bits} bits, {} bytes)",
+        entropy.len()
+    ))?;
+    out.secret_line(hex_case.encode(entropy))?;
+    out.blank()?;
+    out.line(format_args!(
+        "2. Mnemonic: the entropy plus a {}-bit checksum, read as {} 11-bit word indices",
+        bits / 32,
+        mnemonic.word_count()
+    ))?;
+    out.blank()?;
+    super::entropy::print_encoding_table(out, mnemonic, entropy)?;
+    out.blank()?;
+    let mut phrase = mnemonic.to_string();
+    let printed = out.secret_line(&phrase);
+    phrase.zeroize();
+    printed?;
+    out.blank()?;
+    out.line(format_args!(
+        "3. Seed: PBKDF2-HMAC-SHA512 of the mnemonic and {} passphrase (512 bits, 64 bytes)",
+        if passphrase_used { "the" } else { "an empty" }
+    ))?;
+    out.secret_line(encoded_seed)?;
+    out.blank()?;
+    out.line(
+        "Entropy and mnemonic convert back and forth; the seed is one-way and recovers neither.",
+    )
+}
+
+/// The seed header; quiet output still separates the seed from `--show-entropy` hex
+fn print_seed_header(
+    out: &mut Output,
+    passphrase_used: bool,
+    show_entropy: bool,
+) -> io::Result<()> {
+    if out.is_quiet() {
+        return if show_entropy { out.data("") } else { Ok(()) };
+    }
+    if !show_entropy {
+        out.blank()?;
+    }
+    out.section("Derived Seed")?;
+    out.field("Length", "512 bits (64 bytes)")?;
+    out.field("Passphrase", if passphrase_used { "Used" } else { "None" })?;
+    out.blank()
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageChoice};
+use crate::error::CliError;
+use crate::output::Output;
+
+use super::fingerprint::master_fingerprint;
+use super::parse_mnemonic;
+use super::passphrase::resolve_passphrase;
+
+pub fn handle_info(
+    mut mnemonic: String,
+    passphrase: String,
+    passphrase_env: Option<&str>,
+    passphrase_file: Option<&Path>,
+    language: LanguageChoice,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut passphrase = match resolve_passphrase(passphrase, passphrase_env, passphrase_file) {
+        Ok(passphrase) => passphrase,
+        Err(e) => {
+            mnemonic.zeroize();
+            return Err(e);
+        }
+    };
+    let (mnemonic_obj, bip39_language) = match parse_mnemonic(mnemonic, language, global) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            passphrase.zeroize();
+            return Err(e);
+        }
+    };
+
+    let word_count = mnemonic_obj.word_count();
+    let entropy_bits = word_count / 3 * 32;
+    let mut seed = mnemonic_obj.to_seed(&passphrase);
+    let passphrase_used = !passphrase.is_empty();
+    passphrase.zeroize();
+    let fingerprint = master_fingerprint(&seed);
+    seed.zeroize(); // Clear seed from memory
+    let fingerprint = global.hex_case.encode(fingerprint?.to_bytes());
+
+    let mut out = Output::stdout(global, quiet);
+    if quiet {
+        out.data(format_args!(
+            "{word_count}\t{bip39_language:?}\t{entropy_bits}\t{fingerprint}\t{}",
+            if passphrase_used { "used" } else { "none" }
+        ))?;
+        return Ok(());
+    }
+
+    out.section("Mnemonic Info")?;
+    out.field("Words", word_count)?;
+    if language == LanguageChoice::Auto {
+        out.field("Language", format_args!("{bip39_language:?} (detected)"))?;
+    } else {
+        out.field("Language", format_args!("{bip39_language:?}"))?;
+    }
+    out.field("Entropy", format_args!("{entropy_bits} bits"))?;
+    out.field("Checksum", format_args!("{} bits", word_count / 3))?;
+    out.field("Passphrase", if passphrase_used { "Used" } else { "None" })?;
+    out.field("Master fingerprint", &fingerprint)?;
+    out.blank()?;
+    out.line("💡 A different passphrase gives a different wallet and fingerprint;")?;
+    out.line("   if this fingerprint doesn't match your wallet, it expects a passphrase.")?;
+
+    Ok(())
+}
@@ -1,66 +1,582 @@
+pub mod address;
+pub mod bench;
+pub mod compliance;
+pub mod decrypt;
+pub mod diff;
 pub mod entropy;
+pub mod fingerprint;
 pub mod generate;
+pub mod info;
+pub mod passphrase;
 pub mod seed;
+pub mod strength;
 pub mod validate;
+pub mod verify;
+pub mod words;
+pub mod xprv;
 
-use crate::cli::Commands;
+use std::time::Instant;
+
+use bip39::Mnemonic;
+use zeroize::Zeroize;
+
+use crate::cli::{Commands, GlobalArgs, LanguageChoice, WordLayout};
 use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{
+    check_words, detect_language, normalize_mnemonic, validate_mnemonic_word_count,
+};
+
+/// Print the primary result of a command, or write it to `--output-file` if given
+pub fn emit_secret(out: &mut Output, value: &str, global: &GlobalArgs) -> Result<(), CliError> {
+    match &global.output_file {
+        Some(path) => {
+            let overwrite = global.force
+                || (path.exists()
+                    && crate::security::confirm(
+                        &format!("Overwrite existing file {}?", path.display()),
+                        global.assume_yes,
+                    )?);
+            crate::security::write_secret_file(path, value, overwrite)?;
+            out.field("Written to", path.display())?;
+        }
+        None => out.secret(value)?,
+    }
+    Ok(())
+}
+
+/// Run one stage of a command, reporting its wall-clock time on stderr with `--profile`
+pub fn profile<T>(global: &GlobalArgs, stage: &str, f: impl FnOnce() -> T) -> T {
+    if !global.profile {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    eprintln!("⏱️  {stage}: {:.3} ms", elapsed.as_secs_f64() * 1000.0);
+    result
+}
+
+/// Load a `--words-file` word list, warning that the result is not standard BIP39
+pub fn load_custom_word_list(path: &std::path::Path) -> Result<Vec<String>, CliError> {
+    let words = crate::security::read_word_list_file(path)?;
+    eprintln!(
+        "⚠️  WARNING: using the custom word list {}; mnemonics spelled with it are NOT standard BIP39 and other wallets will not accept them",
+        path.display()
+    );
+    Ok(words)
+}
+
+/// Mask a word to its first character followed by an asterisk per remaining character
+#[must_use]
+pub fn redact_word(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => std::iter::once(first).chain(chars.map(|_| '*')).collect(),
+        None => String::new(),
+    }
+}
+
+/// Redact every word of a phrase, keeping the word count
+#[must_use]
+pub fn redact_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(redact_word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Arrange mnemonic words according to `--numbered`, `--columns` and `--redact`
+pub fn format_mnemonic(phrase: &str, layout: WordLayout) -> String {
+    let cells: Vec<String> = phrase
+        .split_whitespace()
+        .enumerate()
+        .map(|(i, word)| {
+            let word = if layout.redact {
+                redact_word(word)
+            } else {
+                word.to_string()
+            };
+            if layout.numbered {
+                format!("{:>2}. {word}", i + 1)
+            } else {
+                word
+            }
+        })
+        .collect();
 
-pub fn run_command(command: Commands) -> Result<(), CliError> {
+    let Some(columns) = layout.columns else {
+        let separator = if layout.numbered { "\n" } else { " " };
+        return cells.join(separator);
+    };
+
+    let columns = usize::from(columns);
+    let rows = cells.len().div_ceil(columns);
+    let width = cells
+        .iter()
+        .map(|cell| cell.chars().count())
+        .max()
+        .unwrap_or(0);
+    (0..rows)
+        .map(|row| {
+            cells
+                .iter()
+                .skip(row)
+                .step_by(rows.max(1))
+                .map(|cell| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Emit a mnemonic, laid out for reading unless quiet or writing to a file
+pub fn emit_mnemonic(
+    out: &mut Output,
+    phrase: &str,
+    layout: WordLayout,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    if out.is_quiet() && layout.redact {
+        out.secret(&redact_phrase(phrase))?;
+        return Ok(());
+    }
+    if out.is_quiet() || global.output_file.is_some() {
+        return emit_secret(out, phrase, global);
+    }
+    let mut formatted = format_mnemonic(phrase, layout);
+    let written = out.secret(&formatted);
+    formatted.zeroize();
+    Ok(written?)
+}
+
+/// Reject `--encrypt-to` without `--output-file`, the only sink that takes ciphertext
+///
+/// Checked here rather than by clap, which cannot require a global argument.
+pub fn check_encrypt_to(
+    encrypt_to: Option<&std::path::Path>,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    if encrypt_to.is_some() && global.output_file.is_none() {
+        return Err(CliError::UsageError {
+            message: "--encrypt-to writes the encrypted seed to --output-file, which is missing"
+                .to_string(),
+            hint: "Add --output-file PATH, or drop --encrypt-to".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Parse a mnemonic in `language`, detecting it from the words with `auto`
+///
+/// The input is zeroized whether or not it parses.
+pub fn parse_mnemonic(
+    mut mnemonic: String,
+    language: LanguageChoice,
+    global: &GlobalArgs,
+) -> Result<(Mnemonic, bip39::Language), CliError> {
+    let bip39_language = match language {
+        LanguageChoice::Fixed(language) => Ok(language.into()),
+        LanguageChoice::Auto => detect_language(&mnemonic),
+    };
+    let bip39_language = match bip39_language {
+        Ok(bip39_language) => bip39_language,
+        Err(e) => {
+            mnemonic.zeroize();
+            return Err(e);
+        }
+    };
+    let mut normalized = normalize_mnemonic(&mnemonic, bip39_language);
+    mnemonic.zeroize();
+
+    let parsed = validate_mnemonic_word_count(&normalized)
+        .and_then(|()| {
+            check_words(
+                &normalized,
+                bip39_language,
+                global.suggestions,
+                global.max_edit_distance,
+            )
+        })
+        .and_then(|()| Ok(Mnemonic::parse_in_normalized(bip39_language, &normalized)?));
+    normalized.zeroize();
+    Ok((parsed?, bip39_language))
+}
+
+pub fn run_command(command: Commands, global: &GlobalArgs) -> Result<(), CliError> {
     match command {
         Commands::Generate {
             words,
+            strength,
+            entropy_source,
+            deterministic,
+            extra_entropy,
             language,
+            avoid_words,
+            no_repeats,
             show_entropy,
+            verbose_encoding,
+            histogram,
+            raw,
             show_seed,
+            seed_only,
+            seed_format,
+            encrypt_to,
+            copy,
+            out_fd,
+            clipboard_timeout,
             passphrase,
+            passphrase_env,
+            passphrase_file,
             secure_passphrase,
+            passphrase_confirm,
             analyze_entropy,
+            min_passphrase_score,
+            no_passphrase_check,
+            dry_run,
+            explain,
+            hash_output,
+            hash_salt,
+            count,
+            unique,
+            layout,
             quiet,
-        } => generate::handle_generate(generate::GenerateOptions {
-            words,
-            language,
-            show_entropy,
-            show_seed,
-            passphrase,
-            secure_passphrase,
-            analyze_entropy,
-            quiet,
-        }),
+        } => generate::handle_generate(
+            generate::GenerateOptions {
+                words: words.or(strength),
+                entropy_source,
+                deterministic,
+                extra_entropy,
+                language,
+                avoid_words,
+                no_repeats,
+                show_entropy,
+                verbose_encoding,
+                histogram,
+                raw,
+                show_seed,
+                seed_only,
+                seed_format,
+                encrypt_to,
+                copy,
+                out_fd,
+                clipboard_timeout,
+                passphrase,
+                passphrase_env,
+                passphrase_file,
+                secure_passphrase,
+                passphrase_confirm,
+                analyze_entropy,
+                min_passphrase_score: (!no_passphrase_check).then_some(min_passphrase_score),
+                dry_run,
+                explain,
+                hash_output,
+                hash_salt,
+                count: count.map(|count| count as usize),
+                unique,
+                layout,
+                quiet,
+            },
+            global,
+        ),
 
         Commands::Validate {
             mnemonic,
+            input_file,
+            stdin_list,
+            null,
+            min_words,
+            max_words,
             language,
             secure_input,
+            show_normalized,
+            redact,
+            checksum_only,
+            verbose,
+            warn_duplicates,
+            expected_word_count,
+            words_file,
             quiet,
-        } => validate::handle_validate(mnemonic, language, secure_input, quiet),
+        } => validate::handle_validate(
+            validate::ValidateOptions {
+                mnemonic,
+                input_file,
+                stdin_list,
+                null,
+                min_words,
+                max_words,
+                language,
+                secure_input,
+                show_normalized,
+                redact,
+                checksum_only,
+                verbose,
+                warn_duplicates,
+                expected_word_count,
+                words_file,
+                quiet,
+            },
+            global,
+        ),
 
         Commands::Seed {
             mnemonic,
+            input_file,
             passphrase,
+            passphrase_env,
+            passphrase_file,
             secure_input,
+            passphrase_confirm,
             analyze_passphrase,
+            min_passphrase_score,
+            no_passphrase_check,
+            show_normalized,
+            explain,
+            reveal,
+            kdf_rounds,
+            scheme,
+            seed_format,
+            encrypt_to,
             language,
             quiet,
         } => seed::handle_seed(
+            seed::SeedOptions {
+                mnemonic,
+                input_file,
+                passphrase,
+                passphrase_env,
+                passphrase_file,
+                secure_input,
+                passphrase_confirm,
+                analyze_passphrase,
+                min_passphrase_score: (!no_passphrase_check).then_some(min_passphrase_score),
+                show_normalized,
+                explain,
+                reveal,
+                kdf_rounds,
+                scheme,
+                seed_format,
+                encrypt_to,
+                language,
+                quiet,
+            },
+            global,
+        ),
+
+        Commands::Decrypt {
+            path,
+            keyfile,
+            seed_format,
+            quiet,
+        } => decrypt::handle_decrypt(&path, &keyfile, seed_format, global, quiet),
+
+        Commands::FromEntropy {
+            entropy,
+            input_file,
+            min_entropy_score,
+            byte_order,
+            pad_entropy,
+            words_file,
+            all_languages,
+            layout,
+            language,
+            quiet,
+        } => entropy::handle_from_entropy(
+            entropy::FromEntropyOptions {
+                entropy,
+                input_file,
+                min_entropy_score,
+                byte_order,
+                pad_entropy,
+                words_file,
+                all_languages,
+                layout,
+                language,
+                quiet,
+            },
+            global,
+        ),
+
+        Commands::FromIndices {
+            indices,
+            grid_file,
+            language,
+            quiet,
+        } => entropy::handle_from_indices(indices, grid_file, language, global, quiet),
+
+        Commands::FromImage {
+            path,
+            words,
+            lsb,
+            min_entropy_score,
+            language,
+            quiet,
+        } => entropy::handle_from_image(
+            &path,
+            words,
+            lsb,
+            min_entropy_score,
+            language,
+            global,
+            quiet,
+        ),
+
+        Commands::Entropy {
+            mnemonic,
+            input_file,
+            show_indices,
+            byte_order,
+            show_checksum,
+            redact,
+            raw,
+            as_dice,
+            words_file,
+            language,
+            quiet,
+        } => entropy::handle_entropy(
+            entropy::EntropyOptions {
+                mnemonic,
+                input_file,
+                show_indices,
+                byte_order,
+                show_checksum,
+                redact,
+                raw,
+                as_dice,
+                words_file,
+                language,
+                quiet,
+            },
+            global,
+        ),
+
+        Commands::Address {
             mnemonic,
             passphrase,
-            secure_input,
-            analyze_passphrase,
+            purpose,
+            index,
             language,
             quiet,
+        } => address::handle_address(
+            mnemonic, passphrase, purpose, index, language, global, quiet,
         ),
 
-        Commands::FromEntropy {
-            entropy,
+        Commands::SeedToXprv {
+            seed,
+            network,
+            quiet,
+        } => xprv::handle_seed_to_xprv(seed, network, global, quiet),
+
+        Commands::Fingerprint {
+            mnemonic,
+            passphrase,
             language,
             quiet,
-        } => entropy::handle_from_entropy(entropy, language, quiet),
+        } => fingerprint::handle_fingerprint(mnemonic, passphrase, language, global, quiet),
 
-        Commands::Entropy {
+        Commands::Info {
             mnemonic,
+            passphrase,
+            passphrase_env,
+            passphrase_file,
             language,
             quiet,
-        } => entropy::handle_entropy(mnemonic, language, quiet),
+        } => info::handle_info(
+            mnemonic,
+            passphrase,
+            passphrase_env.as_deref(),
+            passphrase_file.as_deref(),
+            language,
+            global,
+            quiet,
+        ),
+
+        Commands::Strength {
+            mnemonic,
+            passphrase,
+            passphrase_env,
+            passphrase_file,
+            language,
+            quiet,
+        } => strength::handle_strength(
+            mnemonic,
+            passphrase,
+            passphrase_env.as_deref(),
+            passphrase_file.as_deref(),
+            language,
+            global,
+            quiet,
+        ),
+
+        Commands::Diff {
+            first,
+            second,
+            language,
+            quiet,
+        } => diff::handle_diff(first, second, language, global, quiet),
+
+        Commands::Verify {
+            mnemonic,
+            passphrase,
+            expected_seed,
+            expected_fingerprint,
+            language,
+            quiet,
+        } => verify::handle_verify(
+            mnemonic,
+            passphrase,
+            expected_seed,
+            expected_fingerprint,
+            language,
+            global,
+            quiet,
+        ),
+
+        Commands::AnalyzePassphrase {
+            passphrase,
+            secure_input,
+            quiet,
+        } => passphrase::handle_analyze_passphrase(passphrase, secure_input, global, quiet),
+
+        Commands::Dump {
+            language,
+            with_index,
+            hex_index,
+            format,
+        } => words::handle_dump(language, with_index, hex_index, format, global),
+
+        Commands::Words {
+            prefix,
+            index,
+            language,
+            quiet,
+        } => words::handle_words(prefix, index, language, global, quiet),
+
+        Commands::Languages { quiet } => words::handle_languages(global, quiet),
+
+        Commands::Integrity { quiet } => words::handle_integrity(global, quiet),
+
+        Commands::Compliance { quiet } => compliance::handle_compliance(global, quiet),
+
+        Commands::Bench {
+            words,
+            language,
+            iterations,
+            quiet,
+        } => bench::handle_bench(words, language, iterations, global, quiet),
+
+        Commands::AnalyzeEntropy { entropy, quiet } => {
+            entropy::handle_analyze_entropy(entropy, global, quiet)
+        }
+
+        Commands::SelfTest {
+            samples,
+            size,
+            quiet,
+        } => entropy::handle_self_test(samples as usize, size as usize, global, quiet),
     }
 }
@@ -0,0 +1,104 @@
+use std::io;
+use std::path::Path;
+
+use zeroize::Zeroize;
+
+use crate::cli::GlobalArgs;
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{
+    assess_passphrase_strength, read_confirmed, read_passphrase_env, read_passphrase_file,
+    secure_input, PassphraseStrength, TerminalReader,
+};
+
+/// Print the full passphrase strength report
+pub fn print_strength_report(out: &mut Output, strength: &PassphraseStrength) -> io::Result<()> {
+    out.section("🔐 Passphrase Strength Analysis")?;
+    out.field("Score", format_args!("{:.2}/1.0", strength.score))?;
+    out.field("Entropy", format_args!("{:.1} bits", strength.entropy))?;
+
+    if !strength.issues.is_empty() {
+        out.blank()?;
+        out.line("⚠️  Issues:")?;
+        for issue in &strength.issues {
+            out.line(format_args!("  • {issue}"))?;
+        }
+    }
+
+    out.blank()?;
+    out.line("💡 Recommendations:")?;
+    for rec in &strength.recommendations {
+        out.line(format_args!("  • {rec}"))?;
+    }
+    out.blank()
+}
+
+/// Prompt for a hidden passphrase, twice when `confirm` is set
+pub fn prompt_passphrase(confirm: bool, global: &GlobalArgs) -> Result<String, CliError> {
+    let mut reader = TerminalReader::new(global.input_timeout);
+    let prompt = "Enter passphrase for seed derivation:";
+    if confirm {
+        read_confirmed(&mut reader, prompt)
+    } else {
+        secure_input(&mut reader, prompt)
+    }
+}
+
+/// Pick the passphrase source: `--passphrase-env` > `--passphrase-file` > `--passphrase`
+///
+/// Secure (interactive) input is handled by the caller and takes precedence over all of these.
+pub fn resolve_passphrase(
+    mut passphrase: String,
+    passphrase_env: Option<&str>,
+    passphrase_file: Option<&Path>,
+) -> Result<String, CliError> {
+    let resolved = match (passphrase_env, passphrase_file) {
+        (Some(var), _) => read_passphrase_env(var)?,
+        (None, Some(path)) => read_passphrase_file(path)?,
+        (None, None) => return Ok(passphrase),
+    };
+    passphrase.zeroize();
+    Ok(resolved)
+}
+
+/// Reject a passphrase scoring below `min_score`; `None` disables the check
+pub fn enforce_min_score(
+    strength: &PassphraseStrength,
+    min_score: Option<f64>,
+) -> Result<(), CliError> {
+    match min_score {
+        Some(threshold) if strength.score < threshold => Err(CliError::WeakPassphrase {
+            score: strength.score,
+            threshold,
+        }),
+        _ => Ok(()),
+    }
+}
+
+pub fn handle_analyze_passphrase(
+    passphrase: Option<String>,
+    secure_input: bool,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut final_passphrase = if secure_input {
+        crate::security::secure_input(
+            &mut TerminalReader::new(global.input_timeout),
+            "Enter passphrase to analyze:",
+        )?
+    } else {
+        passphrase.unwrap_or_default()
+    };
+
+    let strength = assess_passphrase_strength(&final_passphrase);
+    final_passphrase.zeroize();
+
+    let mut out = Output::stdout(global, quiet);
+    if quiet {
+        out.data(format_args!("{:.2}", strength.score))?;
+    } else {
+        print_strength_report(&mut out, &strength)?;
+    }
+
+    Ok(())
+}
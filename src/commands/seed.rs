@@ -1,71 +1,221 @@
+use std::path::PathBuf;
+
 use bip39::Mnemonic;
+use sha2::Sha512;
+use unicode_normalization::UnicodeNormalization;
 use zeroize::Zeroize;
 
-use crate::cli::LanguageOption;
+use crate::cli::{GlobalArgs, HexCase, LanguageChoice, SeedFormat, SeedScheme};
+use crate::encryption::{encrypt_seed, read_key_file};
 use crate::error::CliError;
-use crate::security::validate_mnemonic_word_count;
-
-pub fn handle_seed(
-    mnemonic: String,
-    passphrase: String,
-    secure_input: bool,
-    analyze_passphrase: bool,
-    language: LanguageOption,
-    quiet: bool,
-) -> Result<(), CliError> {
-    // Handle secure input for mnemonic if requested
-    let final_mnemonic = if secure_input {
-        crate::security::secure_mnemonic_input("Enter mnemonic:", language.into())?
+use crate::memory::{SecretBuffer, SecretString};
+use crate::output::Output;
+use crate::security::{
+    check_words, detect_language, normalize_mnemonic, read_input_file,
+    validate_mnemonic_word_count, TerminalReader,
+};
+
+pub struct SeedOptions {
+    pub mnemonic: Option<String>,
+    pub input_file: Option<PathBuf>,
+    pub passphrase: String,
+    pub passphrase_env: Option<String>,
+    pub passphrase_file: Option<PathBuf>,
+    pub secure_input: bool,
+    pub passphrase_confirm: bool,
+    pub analyze_passphrase: bool,
+    pub min_passphrase_score: Option<f64>,
+    pub show_normalized: bool,
+    pub explain: bool,
+    pub reveal: bool,
+    pub kdf_rounds: Option<u32>,
+    pub scheme: SeedScheme,
+    pub seed_format: SeedFormat,
+    pub encrypt_to: Option<PathBuf>,
+    pub language: LanguageChoice,
+    pub quiet: bool,
+}
+
+/// PBKDF2-HMAC-SHA512 iteration count mandated by BIP39
+pub const BIP39_KDF_ROUNDS: u32 = 2048;
+
+/// Prefix BIP39 puts before the passphrase to form the PBKDF2 salt
+pub const BIP39_SALT_PREFIX: &str = "mnemonic";
+
+/// PBKDF2-HMAC-SHA512 iteration count of the Cardano Icarus scheme
+pub const ICARUS_KDF_ROUNDS: u32 = 4096;
+
+/// Derive the 96-byte Cardano Icarus master key (CIP-3) from mnemonic entropy
+///
+/// Unlike BIP39, the PBKDF2 password is the passphrase and the salt is the raw
+/// entropy. The first 32 bytes are clamped into an Ed25519-BIP32 scalar; the
+/// result is `kL || kR || chain code`.
+#[must_use]
+pub fn cardano_icarus_master_key(entropy: &[u8], passphrase: &str) -> [u8; 96] {
+    let mut key = [0u8; 96];
+    pbkdf2::pbkdf2_hmac::<Sha512>(passphrase.as_bytes(), entropy, ICARUS_KDF_ROUNDS, &mut key);
+    key[0] &= 0b1111_1000;
+    key[31] &= 0b0001_1111;
+    key[31] |= 0b0100_0000;
+    key
+}
+
+/// Encode a seed in the requested text format
+#[must_use]
+pub fn encode_seed(seed: &[u8], format: SeedFormat, hex_case: HexCase) -> String {
+    use base64::Engine;
+
+    match format {
+        SeedFormat::Hex => hex_case.encode(seed),
+        SeedFormat::Base64 => base64::engine::general_purpose::STANDARD.encode(seed),
+        SeedFormat::Base58 => bs58::encode(seed).into_string(),
+    }
+}
+
+/// Show how BIP39 feeds the mnemonic and passphrase into PBKDF2, for `--explain`
+fn print_kdf_explanation(
+    out: &mut Output,
+    normalized_passphrase: &str,
+    reveal: bool,
+    seed_len: usize,
+) -> std::io::Result<()> {
+    out.section("PBKDF2 Parameters")?;
+    out.field("Password", "NFKD(mnemonic) as UTF-8")?;
+    if normalized_passphrase.is_empty() {
+        out.field(
+            "Salt",
+            format_args!(
+                "\"{BIP39_SALT_PREFIX}\" ({} bytes, no passphrase)",
+                BIP39_SALT_PREFIX.len()
+            ),
+        )?;
+    } else if reveal {
+        out.field(
+            "Salt",
+            format_args!(
+                "\"{BIP39_SALT_PREFIX}{normalized_passphrase}\" ({} bytes, \"{BIP39_SALT_PREFIX}\" + NFKD(passphrase))",
+                BIP39_SALT_PREFIX.len() + normalized_passphrase.len()
+            ),
+        )?;
     } else {
-        mnemonic
+        // A fixed mask, so neither the passphrase nor its length is shown
+        out.field(
+            "Salt",
+            format_args!("\"{BIP39_SALT_PREFIX}\" + NFKD(passphrase) (hidden; --reveal shows it)"),
+        )?;
+    }
+    out.field("Iterations", BIP39_KDF_ROUNDS)?;
+    out.field("HMAC", "HMAC-SHA512")?;
+    out.field("Output length", format_args!("{seed_len} bytes"))?;
+    out.blank()
+}
+
+pub fn handle_seed(opts: SeedOptions, global: &GlobalArgs) -> Result<(), CliError> {
+    let SeedOptions {
+        mnemonic,
+        input_file,
+        passphrase,
+        passphrase_env,
+        passphrase_file,
+        secure_input,
+        passphrase_confirm,
+        analyze_passphrase,
+        min_passphrase_score,
+        show_normalized,
+        explain,
+        reveal,
+        kdf_rounds,
+        scheme,
+        seed_format,
+        encrypt_to,
+        language,
+        quiet,
+    } = opts;
+
+    // BIP39 fixes PBKDF2 at 2048 rounds; refuse to pretend otherwise
+    if let Some(rounds) = kdf_rounds.filter(|&rounds| rounds != BIP39_KDF_ROUNDS) {
+        return Err(CliError::UnsupportedKdfRounds { rounds });
+    }
+    super::check_encrypt_to(encrypt_to.as_deref(), global)?;
+    // Read the key before any prompt or PBKDF2 run, so a bad key file fails fast
+    let key = encrypt_to.as_deref().map(read_key_file).transpose()?;
+
+    // Handle secure input or file input for mnemonic if requested
+    let mut raw_mnemonic = match language {
+        LanguageChoice::Fixed(language) if secure_input => crate::security::secure_mnemonic_input(
+            &mut TerminalReader::new(global.input_timeout),
+            "Enter mnemonic:",
+            language.into(),
+            global.suggestions,
+            global.max_edit_distance,
+        )?,
+        // Word suggestions need a word list, so detection checks the words afterwards
+        LanguageChoice::Auto if secure_input => crate::security::secure_input(
+            &mut TerminalReader::new(global.input_timeout),
+            "Enter mnemonic:",
+        )?,
+        _ => match input_file {
+            Some(path) => read_input_file(&path)?,
+            None => mnemonic.unwrap_or_default(),
+        },
     };
+    let bip39_language = match language {
+        LanguageChoice::Fixed(language) => language.into(),
+        LanguageChoice::Auto => match detect_language(&raw_mnemonic) {
+            Ok(detected) => detected,
+            Err(e) => {
+                raw_mnemonic.zeroize();
+                return Err(e);
+            }
+        },
+    };
+    let final_mnemonic = super::profile(global, "normalization", || {
+        normalize_mnemonic(&raw_mnemonic, bip39_language)
+    });
+    raw_mnemonic.zeroize();
 
     validate_mnemonic_word_count(&final_mnemonic)?;
-    let mnemonic_obj = Mnemonic::parse_in_normalized(language.into(), &final_mnemonic)?;
+    check_words(
+        &final_mnemonic,
+        bip39_language,
+        global.suggestions,
+        global.max_edit_distance,
+    )?;
+    let mnemonic_obj = super::profile(global, "parsing", || {
+        Mnemonic::parse_in_normalized(bip39_language, &final_mnemonic)
+    })?;
+
+    // Passphrase precedence: --secure-input > --passphrase-env > --passphrase-file > --passphrase
+    let passphrase = if secure_input {
+        passphrase
+    } else {
+        super::passphrase::resolve_passphrase(
+            passphrase,
+            passphrase_env.as_deref(),
+            passphrase_file.as_deref(),
+        )?
+    };
+    let passphrase = SecretString::from_string(passphrase, global.memory_lock);
+
+    let mut out = Output::stdout(global, quiet);
 
     // Handle secure passphrase input or analysis
     let final_passphrase = if secure_input && passphrase.is_empty() {
-        let mut secure_pass = crate::security::secure_input(
-            "Enter passphrase for seed derivation:",
-        )
-        .map_err(|e| CliError::InvalidHexString {
-            message: format!("Failed to read secure passphrase: {e}"),
-            position: None,
-            hint: "Ensure terminal supports secure input".to_string(),
-        })?;
+        let secure_pass = SecretString::from_string(
+            super::passphrase::prompt_passphrase(passphrase_confirm, global)?,
+            global.memory_lock,
+        );
 
         // Always assess passphrase strength if analyzing or not quiet
         if analyze_passphrase || !quiet {
             let strength = crate::security::assess_passphrase_strength(&secure_pass);
 
             if !quiet {
-                println!("\n🔐 Passphrase Strength Analysis");
-                println!("═══════════════════════════════");
-                println!("Score: {:.2}/1.0", strength.score);
-                println!("Entropy: {:.1} bits", strength.entropy);
-
-                if !strength.issues.is_empty() {
-                    println!("\n⚠️  Issues:");
-                    for issue in &strength.issues {
-                        println!("  • {issue}");
-                    }
-                }
-
-                println!("\n💡 Recommendations:");
-                for rec in &strength.recommendations {
-                    println!("  • {rec}");
-                }
-                println!();
+                out.blank()?;
+                super::passphrase::print_strength_report(&mut out, &strength)?;
             }
 
-            if strength.score < 0.6 {
-                secure_pass.zeroize();
-                return Err(CliError::InvalidHexString {
-                    message: "Passphrase strength too low".to_string(),
-                    position: None,
-                    hint: "Use a longer, more complex passphrase".to_string(),
-                });
-            }
+            super::passphrase::enforce_min_score(&strength, min_passphrase_score)?;
         }
 
         secure_pass
@@ -73,59 +223,96 @@ pub fn handle_seed(
         let strength = crate::security::assess_passphrase_strength(&passphrase);
 
         if !quiet {
-            println!("\n🔐 Passphrase Strength Analysis");
-            println!("═══════════════════════════════");
-            println!("Score: {:.2}/1.0", strength.score);
-            println!("Entropy: {:.1} bits", strength.entropy);
-
-            if !strength.issues.is_empty() {
-                println!("\n⚠️  Issues:");
-                for issue in &strength.issues {
-                    println!("  • {issue}");
-                }
-            }
-
-            println!("\n💡 Recommendations:");
-            for rec in &strength.recommendations {
-                println!("  • {rec}");
-            }
-            println!();
+            out.blank()?;
+            super::passphrase::print_strength_report(&mut out, &strength)?;
         }
 
-        if strength.score < 0.6 {
-            return Err(CliError::InvalidHexString {
-                message: "Passphrase strength too low".to_string(),
-                position: None,
-                hint: "Use a longer, more complex passphrase".to_string(),
-            });
-        }
+        super::passphrase::enforce_min_score(&strength, min_passphrase_score)?;
 
         passphrase
     } else {
         passphrase
     };
 
-    let mut seed = mnemonic_obj.to_seed(&final_passphrase);
+    let mut seed = match scheme {
+        SeedScheme::Bip39 => {
+            let mut seed = super::profile(global, "to_seed (PBKDF2)", || {
+                mnemonic_obj.to_seed(&*final_passphrase)
+            });
+            let bytes = SecretBuffer::from_slice(&seed, global.memory_lock);
+            seed.zeroize();
+            bytes
+        }
+        SeedScheme::CardanoIcarus => {
+            let mut entropy = mnemonic_obj.to_entropy();
+            let mut key = super::profile(global, "Icarus master key (PBKDF2)", || {
+                cardano_icarus_master_key(&entropy, &final_passphrase)
+            });
+            entropy.zeroize();
+            let bytes = SecretBuffer::from_slice(&key, global.memory_lock);
+            key.zeroize();
+            bytes
+        }
+    };
+    let passphrase_used = !final_passphrase.is_empty();
+    let mut normalized_passphrase: String = if (show_normalized || explain) && !quiet {
+        final_passphrase.nfkd().collect()
+    } else {
+        String::new()
+    };
+    drop(final_passphrase);
 
     if !quiet {
         let entropy = mnemonic_obj.to_entropy();
         let word_count = final_mnemonic.split_whitespace().count();
         let entropy_bits = entropy.len() * 8;
-        println!("Seed Generation");
-        println!("════════════════");
-        println!("Input words: {word_count}");
-        println!("Input entropy: {entropy_bits} bits");
-        println!("Output: 512 bits (64 bytes)");
-        if final_passphrase.is_empty() {
-            println!("Passphrase: None");
+        if scheme == SeedScheme::CardanoIcarus {
+            out.section("Cardano Master Key (Icarus)")?;
+            out.line("⚠️  Not a BIP39 seed: use only with Cardano wallets")?;
         } else {
-            println!("Passphrase: Used");
+            out.section_with_rule("Seed Generation", 16)?;
+        }
+        if language == LanguageChoice::Auto {
+            out.field("Language", format_args!("{bip39_language:?} (detected)"))?;
+        }
+        out.field("Input words", word_count)?;
+        out.field("Input entropy", format_args!("{entropy_bits} bits"))?;
+        out.field(
+            "Output",
+            format_args!("{} bits ({} bytes)", seed.len() * 8, seed.len()),
+        )?;
+        out.field("Passphrase", if passphrase_used { "Used" } else { "None" })?;
+        if show_normalized {
+            // PBKDF2 input: password = NFKD(mnemonic), salt = "mnemonic" + NFKD(passphrase)
+            out.secret_line(format_args!("Normalized mnemonic (NFKD): {final_mnemonic}"))?;
+            out.secret_line(format_args!(
+                "Normalized passphrase (NFKD): \"{normalized_passphrase}\""
+            ))?;
+            out.secret_line(format_args!(
+                "Passphrase bytes (UTF-8): {}",
+                global.hex_case.encode(normalized_passphrase.as_bytes())
+            ))?;
+        }
+        if let Some(path) = &encrypt_to {
+            out.field(
+                "Encrypted with",
+                format_args!("ChaCha20-Poly1305, key file {}", path.display()),
+            )?;
+        }
+        out.blank()?;
+        if explain {
+            print_kdf_explanation(&mut out, &normalized_passphrase, reveal, seed.len())?;
         }
-        println!();
     }
-    let encoded_seed = hex::encode(seed);
-    println!("{encoded_seed}");
+    normalized_passphrase.zeroize();
+    let mut encoded_seed = match &key {
+        Some(key) => encrypt_seed(&seed, key),
+        None => encode_seed(&seed, seed_format, global.hex_case),
+    };
     seed.zeroize(); // Clear seed from memory
+    let emitted = super::emit_secret(&mut out, &encoded_seed, global);
+    encoded_seed.zeroize();
+    emitted?;
 
     Ok(())
 }
@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageChoice};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::assess_passphrase_strength;
+
+use super::parse_mnemonic;
+use super::passphrase::{print_strength_report, resolve_passphrase};
+
+pub fn handle_strength(
+    mut mnemonic: String,
+    passphrase: String,
+    passphrase_env: Option<&str>,
+    passphrase_file: Option<&Path>,
+    language: LanguageChoice,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut passphrase = match resolve_passphrase(passphrase, passphrase_env, passphrase_file) {
+        Ok(passphrase) => passphrase,
+        Err(e) => {
+            mnemonic.zeroize();
+            return Err(e);
+        }
+    };
+    let mnemonic_obj = match parse_mnemonic(mnemonic, language, global) {
+        Ok((mnemonic_obj, _)) => mnemonic_obj,
+        Err(e) => {
+            passphrase.zeroize();
+            return Err(e);
+        }
+    };
+
+    let mut entropy = mnemonic_obj.to_entropy();
+    let mnemonic_bits = entropy.len() * 8;
+    entropy.zeroize(); // Clear entropy from memory
+
+    // An empty passphrase adds nothing, so it is not assessed at all
+    let strength = (!passphrase.is_empty()).then(|| assess_passphrase_strength(&passphrase));
+    passphrase.zeroize();
+    let passphrase_bits = strength.as_ref().map_or(0.0, |strength| strength.entropy);
+    let effective_bits = mnemonic_bits as f64 + passphrase_bits;
+
+    let mut out = Output::stdout(global, quiet);
+    if quiet {
+        out.data(format_args!("{effective_bits:.1}"))?;
+        return Ok(());
+    }
+
+    out.section("Backup Strength")?;
+    out.field(
+        "Mnemonic",
+        format_args!("{} words, {mnemonic_bits} bits", mnemonic_obj.word_count()),
+    )?;
+    match &strength {
+        Some(strength) => out.field(
+            "Passphrase",
+            format_args!("~{:.1} bits (estimated)", strength.entropy),
+        )?,
+        None => out.field("Passphrase", "none")?,
+    }
+    out.field(
+        "Effective entropy",
+        format_args!("{effective_bits:.1} bits"),
+    )?;
+    out.blank()?;
+    match &strength {
+        Some(strength) => print_strength_report(&mut out, strength)?,
+        None => {
+            out.line("💡 Without a passphrase, anyone holding the mnemonic holds the wallet.")?;
+            out.blank()?;
+        }
+    }
+    out.line(
+        "💡 The passphrase figure assumes random characters; words or patterns count for less.",
+    )?;
+    out.line("   Keys derived from the seed stay at about 128-bit security whatever the total.")?;
+
+    Ok(())
+}
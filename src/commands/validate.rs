@@ -1,65 +1,483 @@
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
 use bip39::Mnemonic;
+use unicode_normalization::is_nfkd;
+use zeroize::Zeroize;
 
-use crate::cli::LanguageOption;
+use crate::cli::{GlobalArgs, LanguageOption, WordCount};
 use crate::error::CliError;
-use crate::security::{find_invalid_words, validate_mnemonic_word_count};
+use crate::output::Output;
+use crate::security::{
+    detect_electrum_seed, find_invalid_words, find_suspicious_characters, normalize_mnemonic,
+    parse_custom_mnemonic, read_input_file, repeated_words, validate_mnemonic_word_count,
+    verify_checksum, SuspiciousChar, TerminalReader,
+};
 
-pub fn handle_validate(
-    mnemonic: String,
-    language: LanguageOption,
-    secure_input: bool,
-    quiet: bool,
-) -> Result<(), CliError> {
-    let final_mnemonic = if secure_input {
-        crate::security::secure_mnemonic_input("Enter mnemonic to validate:", language.into())?
+pub struct ValidateOptions {
+    pub mnemonic: Option<String>,
+    pub input_file: Option<PathBuf>,
+    pub stdin_list: bool,
+    pub null: bool,
+    pub min_words: Option<WordCount>,
+    pub max_words: Option<WordCount>,
+    pub language: LanguageOption,
+    pub secure_input: bool,
+    pub show_normalized: bool,
+    pub redact: bool,
+    pub checksum_only: bool,
+    pub verbose: bool,
+    pub warn_duplicates: bool,
+    pub expected_word_count: Option<WordCount>,
+    pub words_file: Option<PathBuf>,
+    pub quiet: bool,
+}
+
+pub fn handle_validate(opts: ValidateOptions, global: &GlobalArgs) -> Result<(), CliError> {
+    let ValidateOptions {
+        mnemonic,
+        input_file,
+        stdin_list,
+        null,
+        min_words,
+        max_words,
+        language,
+        secure_input,
+        show_normalized,
+        redact,
+        checksum_only,
+        verbose,
+        warn_duplicates,
+        expected_word_count,
+        words_file,
+        quiet,
+    } = opts;
+    let bip39_language = language.into();
+    let mut out = Output::stdout(global, quiet);
+
+    if stdin_list {
+        let range = WordRange {
+            min: min_words,
+            max: max_words,
+        };
+        if let (Some(min), Some(max)) = (min_words, max_words) {
+            if min.to_word_count() > max.to_word_count() {
+                return Err(CliError::UsageError {
+                    message: format!(
+                        "--min-words {} is greater than --max-words {}; no phrase could pass",
+                        min.to_word_count(),
+                        max.to_word_count()
+                    ),
+                    hint: "Swap the two values, or drop one of them".to_string(),
+                });
+            }
+        }
+        return validate_stdin_list(&mut out, language, null, checksum_only, range);
+    }
+
+    let mut raw_mnemonic = if secure_input {
+        crate::security::secure_mnemonic_input(
+            &mut TerminalReader::new(global.input_timeout),
+            "Enter mnemonic to validate:",
+            bip39_language,
+            global.suggestions,
+            global.max_edit_distance,
+        )?
+    } else if let Some(path) = input_file {
+        read_input_file(&path)?
     } else {
-        mnemonic
+        mnemonic.unwrap_or_default()
     };
-    validate_mnemonic_word_count(&final_mnemonic)?;
+    let mut final_mnemonic = normalize_mnemonic(&raw_mnemonic, bip39_language);
+    let nfkd_changed = !is_nfkd(&raw_mnemonic);
+    let normalization = show_normalized.then(|| NormalizationReport {
+        modified: raw_mnemonic.trim() != final_mnemonic,
+        suspicious: find_suspicious_characters(&raw_mnemonic, bip39_language),
+    });
+    raw_mnemonic.zeroize();
+
+    if warn_duplicates {
+        warn_repeated_words(&final_mnemonic, redact);
+    }
+
+    if let Some(expected) = expected_word_count {
+        if let Err(e) = check_expected_word_count(&final_mnemonic, expected) {
+            final_mnemonic.zeroize();
+            return Err(e);
+        }
+    }
+
+    if let Some(path) = words_file {
+        let result = super::load_custom_word_list(&path).and_then(|word_list| {
+            report_custom_validation(&mut out, &final_mnemonic, &word_list, redact)
+        });
+        final_mnemonic.zeroize();
+        return result;
+    }
+
+    let result = report_validation(
+        &mut out,
+        &final_mnemonic,
+        language,
+        normalization.as_ref(),
+        redact,
+        checksum_only,
+        global,
+    );
+    final_mnemonic.zeroize(); // Clear mnemonic from memory on every path
+
+    if verbose && result.is_ok() {
+        print_language_details(&mut out, language, nfkd_changed)?;
+    }
+    result
+}
+
+/// Occurrences of one word that `--warn-duplicates` reports
+///
+/// Drawing 12 to 24 words from 2048, some word appears twice in 3-13% of
+/// mnemonics, but three times in under 0.05%; three or more is far likelier
+/// to be a transcription slip than chance.
+const DUPLICATE_WARNING_COUNT: usize = 3;
+
+/// Warn on stderr about words repeated more often than chance explains
+fn warn_repeated_words(mnemonic: &str, redact: bool) {
+    for (word, count) in repeated_words(mnemonic, DUPLICATE_WARNING_COUNT) {
+        let shown = if redact {
+            super::redact_word(word)
+        } else {
+            word.to_string()
+        };
+        eprintln!(
+            "⚠️  Warning: '{shown}' appears {count} times; repeats like this are rare by chance, so check the transcription"
+        );
+    }
+}
+
+/// Require exactly `expected` words, on top of the general word count check
+fn check_expected_word_count(mnemonic: &str, expected: WordCount) -> Result<(), CliError> {
+    validate_mnemonic_word_count(mnemonic)?;
+    let actual = mnemonic.split_whitespace().count();
+    if actual != expected.to_word_count() {
+        return Err(CliError::UnexpectedWordCount {
+            actual,
+            expected: expected.to_word_count(),
+        });
+    }
+    Ok(())
+}
+
+/// Word count policy applied to batch records by `--min-words` and `--max-words`
+#[derive(Clone, Copy)]
+struct WordRange {
+    min: Option<WordCount>,
+    max: Option<WordCount>,
+}
+
+impl WordRange {
+    fn is_bounded(self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    fn contains(self, words: usize) -> bool {
+        let min = self.min.map_or(0, WordCount::to_word_count);
+        let max = self.max.map_or(usize::MAX, WordCount::to_word_count);
+        (min..=max).contains(&words)
+    }
+}
+
+impl std::fmt::Display for WordRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                write!(f, "{} to {}", min.to_word_count(), max.to_word_count())
+            }
+            (Some(min), None) => write!(f, "at least {}", min.to_word_count()),
+            (None, Some(max)) => write!(f, "at most {}", max.to_word_count()),
+            (None, None) => write!(f, "any number of"),
+        }
+    }
+}
+
+/// Validate one mnemonic per stdin record, skipping blank records
+///
+/// Records are lines, or NUL-terminated with `null` so that phrases may
+/// contain any whitespace. Valid phrases whose length falls outside `range`
+/// are reported separately as policy violations and still fail the batch.
+fn validate_stdin_list(
+    out: &mut Output,
+    language: LanguageOption,
+    null: bool,
+    checksum_only: bool,
+    range: WordRange,
+) -> Result<(), CliError> {
+    let bip39_language = language.into();
+    let delimiter = if null { b'\0' } else { b'\n' };
+    let mut stdin = std::io::stdin().lock();
+    let mut record = Vec::new();
+    let mut line_number = 0;
+    let mut valid = 0;
+    let mut invalid = 0;
+    let mut out_of_range = 0;
+
+    loop {
+        record.zeroize();
+        record.clear();
+        if stdin.read_until(delimiter, &mut record)? == 0 {
+            break;
+        }
+        line_number += 1;
+        if record.last() == Some(&delimiter) {
+            record.pop();
+        }
+
+        // Invalid UTF-8 becomes U+FFFD, which no word list contains
+        let mut normalized = normalize_mnemonic(&String::from_utf8_lossy(&record), bip39_language);
+        record.zeroize();
+        if normalized.is_empty() {
+            continue;
+        }
 
+        let is_valid = if checksum_only {
+            verify_checksum(&normalized, bip39_language).is_ok()
+        } else {
+            Mnemonic::parse_in_normalized(bip39_language, &normalized).is_ok()
+        };
+        let words = normalized.split_whitespace().count();
+        normalized.zeroize();
+
+        if is_valid && !range.contains(words) {
+            out_of_range += 1;
+            out.data(format_args!(
+                "{line_number}: policy-invalid ({words} words; policy allows {range} words)"
+            ))?;
+        } else if is_valid {
+            valid += 1;
+            out.data(format_args!("{line_number}: valid"))?;
+        } else {
+            invalid += 1;
+            out.data(format_args!("{line_number}: invalid"))?;
+        }
+    }
+
+    let total = valid + invalid + out_of_range;
+    out.blank()?;
+    out.section("Batch Validation Summary")?;
+    out.field("Checked", total)?;
+    out.field("Valid", valid)?;
+    out.field("Invalid", invalid)?;
+    if range.is_bounded() {
+        out.field("Outside word range", out_of_range)?;
+    }
+    out.field("Language", format_args!("{language:?}"))?;
+
+    if invalid + out_of_range > 0 {
+        return Err(CliError::BatchValidationFailed {
+            invalid: invalid + out_of_range,
+            total,
+        });
+    }
+    Ok(())
+}
+
+/// Word list metadata shown by `validate --verbose`
+fn print_language_details(
+    out: &mut Output,
+    language: LanguageOption,
+    nfkd_changed: bool,
+) -> io::Result<()> {
+    let word_list = bip39::Language::from(language).word_list();
+    out.field("Word list", format_args!("{} words", word_list.len()))?;
+    out.field("Script", language.script())?;
+    out.field(
+        "Normalization",
+        if nfkd_changed {
+            "NFKD changed the input (composed characters were decomposed)"
+        } else {
+            "input was already NFKD"
+        },
+    )
+}
+
+/// How `--show-normalized` found the input rewritten on its way to the parser
+struct NormalizationReport {
+    modified: bool,
+    suspicious: Vec<SuspiciousChar>,
+}
+
+/// Say whether normalization changed the input, listing characters worth a closer look
+fn print_normalization_report(out: &mut Output, report: &NormalizationReport) -> io::Result<()> {
+    out.field("Modified", if report.modified { "yes" } else { "no" })?;
+    if report.suspicious.is_empty() {
+        return Ok(());
+    }
+    out.field("Suspicious characters", report.suspicious.len())?;
+    for suspicious in &report.suspicious {
+        out.line(format_args!(
+            "  position {}: U+{:04X} {}",
+            suspicious.position,
+            u32::from(suspicious.character),
+            suspicious.reason
+        ))?;
+    }
+    out.line("💡 Retype the phrase by hand if you did not expect these characters")
+}
+
+fn report_validation(
+    out: &mut Output,
+    final_mnemonic: &str,
+    language: LanguageOption,
+    normalization: Option<&NormalizationReport>,
+    redact: bool,
+    checksum_only: bool,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
     let bip39_language = language.into();
 
-    match Mnemonic::parse_in_normalized(bip39_language, &final_mnemonic) {
+    if let Some(report) = normalization {
+        let shown = if redact {
+            super::redact_phrase(final_mnemonic)
+        } else {
+            final_mnemonic.to_string()
+        };
+        if out.is_quiet() {
+            out.secret_line(&shown)?;
+        } else {
+            out.secret_line(format_args!("Normalized input: {shown}"))?;
+            print_normalization_report(out, report)?;
+            out.blank()?;
+        }
+    }
+    validate_mnemonic_word_count(final_mnemonic)?;
+
+    if checksum_only {
+        return match verify_checksum(final_mnemonic, bip39_language) {
+            Ok(()) => {
+                if out.is_quiet() {
+                    out.data("valid")?;
+                } else {
+                    out.field("✓ Status", "Valid BIP39 checksum")?;
+                }
+                Ok(())
+            }
+            Err(e) => report_invalid(out, final_mnemonic, language, e, redact, global),
+        };
+    }
+
+    match Mnemonic::parse_in_normalized(bip39_language, final_mnemonic) {
         Ok(parsed_mnemonic) => {
-            if quiet {
-                println!("valid");
+            if out.is_quiet() {
+                out.data("valid")?;
             } else {
                 let entropy = parsed_mnemonic.to_entropy();
                 let word_count = final_mnemonic.split_whitespace().count();
                 let bits = entropy.len() * 8;
-                println!("Mnemonic Validation");
-                println!("═══════════════════");
-                println!("✓ Status: Valid BIP39 mnemonic");
-                println!("Words: {word_count}");
-                println!("Entropy: {bits} bits");
-                println!("Language: {language:?}");
+                out.section("Mnemonic Validation")?;
+                out.field("✓ Status", "Valid BIP39 mnemonic")?;
+                out.field("Words", word_count)?;
+                out.field("Entropy", format_args!("{bits} bits"))?;
+                out.field("Language", format_args!("{language:?}"))?;
             }
             Ok(())
         }
-        Err(e) => {
-            // Check for invalid words first and provide helpful feedback
-            let invalid_words = find_invalid_words(&final_mnemonic, bip39_language);
-            if !invalid_words.is_empty() {
-                let (position, word, suggestions) = &invalid_words[0];
-                return Err(CliError::InvalidWord {
-                    word: word.clone(),
-                    position: *position,
-                    suggestions: suggestions.clone(),
-                });
-            }
+        Err(e) => report_invalid(out, final_mnemonic, language, e, redact, global),
+    }
+}
+
+/// Validate a mnemonic spelled with a `--words-file` word list
+fn report_custom_validation(
+    out: &mut Output,
+    final_mnemonic: &str,
+    word_list: &[String],
+    redact: bool,
+) -> Result<(), CliError> {
+    validate_mnemonic_word_count(final_mnemonic)?;
 
-            if quiet {
-                println!("invalid");
+    match parse_custom_mnemonic(final_mnemonic, word_list) {
+        Ok(_) => {
+            if out.is_quiet() {
+                out.data("valid")?;
             } else {
-                let word_count = final_mnemonic.split_whitespace().count();
-                println!("Mnemonic Validation");
-                println!("═══════════════════");
-                println!("✗ Status: Invalid BIP39 mnemonic");
-                println!("Words: {word_count}");
-                println!("Error: {e}");
-                println!("Language: {language:?}");
+                out.section("Mnemonic Validation")?;
+                out.field(
+                    "✓ Status",
+                    "Valid mnemonic (custom word list, BIP39 checksum)",
+                )?;
+                out.field("Words", final_mnemonic.split_whitespace().count())?;
+            }
+            Ok(())
+        }
+        Err(bip39::Error::UnknownWord(index)) => {
+            let word = final_mnemonic
+                .split_whitespace()
+                .nth(index)
+                .unwrap_or_default();
+            Err(CliError::InvalidWord {
+                word: if redact {
+                    super::redact_word(word)
+                } else {
+                    word.to_string()
+                },
+                position: index + 1,
+                suggestions: Vec::new(),
+            })
+        }
+        Err(e) => {
+            if out.is_quiet() {
+                out.data("invalid")?;
             }
-            std::process::exit(1);
+            Err(CliError::InvalidMnemonic(e))
         }
     }
 }
+
+fn report_invalid(
+    out: &mut Output,
+    final_mnemonic: &str,
+    language: LanguageOption,
+    e: bip39::Error,
+    redact: bool,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    // Check for invalid words first and provide helpful feedback
+    let invalid_words = find_invalid_words(
+        final_mnemonic,
+        language.into(),
+        global.suggestions,
+        global.max_edit_distance,
+    );
+    if let Some((position, word, suggestions)) = invalid_words.into_iter().next() {
+        // Suggestions are close to the real word, so redaction drops them too
+        return Err(if redact {
+            CliError::InvalidWord {
+                word: super::redact_word(&word),
+                position,
+                suggestions: Vec::new(),
+            }
+        } else {
+            CliError::InvalidWord {
+                word,
+                position,
+                suggestions,
+            }
+        });
+    }
+
+    if out.is_quiet() {
+        out.data("invalid")?;
+    } else {
+        let word_count = final_mnemonic.split_whitespace().count();
+        out.section("Mnemonic Validation")?;
+        out.field("✗ Status", "Invalid BIP39 mnemonic")?;
+        out.field("Words", word_count)?;
+        out.field("Error", e)?;
+        out.field("Language", format_args!("{language:?}"))?;
+    }
+
+    // All words are valid BIP39 words, so this may be an Electrum seed
+    if let Some(seed_type) = detect_electrum_seed(final_mnemonic) {
+        return Err(CliError::ElectrumSeed {
+            seed_type: seed_type.to_string(),
+        });
+    }
+    Err(CliError::InvalidMnemonic(e))
+}
@@ -0,0 +1,96 @@
+use bip39::Mnemonic;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, LanguageOption};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{check_words, normalize_mnemonic, validate_mnemonic_word_count};
+
+/// Decode an expected value and check it has the right length
+fn decode_expected(hex_str: &str, expected_len: usize, what: &str) -> Result<Vec<u8>, CliError> {
+    let mut bytes = hex::decode(hex_str)?;
+    if bytes.len() != expected_len {
+        let actual = bytes.len() * 2;
+        bytes.zeroize();
+        return Err(CliError::InvalidHexString {
+            message: format!(
+                "Expected {what} must be {} hex characters, got {actual}",
+                expected_len * 2
+            ),
+            position: None,
+            hint: format!("A BIP39 {what} is {expected_len} bytes"),
+        });
+    }
+    Ok(bytes)
+}
+
+pub fn handle_verify(
+    mnemonic: String,
+    mut passphrase: String,
+    expected_seed: Option<String>,
+    expected_fingerprint: Option<String>,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let mut mnemonic = normalize_mnemonic(&mnemonic, language.into());
+    let parsed = validate_mnemonic_word_count(&mnemonic)
+        .and_then(|()| {
+            check_words(
+                &mnemonic,
+                language.into(),
+                global.suggestions,
+                global.max_edit_distance,
+            )
+        })
+        .and_then(|()| Ok(Mnemonic::parse_in_normalized(language.into(), &mnemonic)?));
+    mnemonic.zeroize();
+    let mnemonic_obj = match parsed {
+        Ok(mnemonic_obj) => mnemonic_obj,
+        Err(e) => {
+            passphrase.zeroize();
+            return Err(e);
+        }
+    };
+
+    let (target, mut expected_hex, expected_len) = match expected_seed {
+        Some(seed) => ("seed", seed, 64),
+        None => ("fingerprint", expected_fingerprint.unwrap_or_default(), 4),
+    };
+    let expected = decode_expected(&expected_hex, expected_len, target);
+    expected_hex.zeroize();
+    let mut expected = expected?;
+
+    let mut seed = mnemonic_obj.to_seed(&passphrase);
+    passphrase.zeroize();
+    let mut actual = if target == "seed" {
+        seed.to_vec()
+    } else {
+        match super::fingerprint::master_fingerprint(&seed) {
+            Ok(fingerprint) => fingerprint.to_bytes().to_vec(),
+            Err(e) => {
+                seed.zeroize();
+                expected.zeroize();
+                return Err(e);
+            }
+        }
+    };
+    seed.zeroize(); // Clear seed from memory
+
+    let matches: bool = actual.ct_eq(&expected).into();
+    actual.zeroize();
+    expected.zeroize();
+
+    // The verdict is the whole output, so --quiet only silences the warnings
+    let mut out = Output::stdout(global, quiet);
+    if matches {
+        out.data("match")?;
+        Ok(())
+    } else {
+        out.data("mismatch")?;
+        Err(CliError::VerificationMismatch {
+            target: target.to_string(),
+        })
+    }
+}
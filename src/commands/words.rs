@@ -0,0 +1,160 @@
+use clap::ValueEnum;
+
+use crate::cli::{DumpFormat, GlobalArgs, LanguageOption};
+use crate::error::CliError;
+use crate::output::Output;
+use crate::security::{
+    word_list_mismatches, word_list_sha256, words_with_prefix, WORD_LIST_SHA256,
+};
+
+pub fn handle_words(
+    prefix: Option<String>,
+    index: Option<usize>,
+    language: LanguageOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let bip39_language: bip39::Language = language.into();
+    let mut out = Output::stdout(global, quiet);
+
+    if let Some(index) = index {
+        let word =
+            bip39_language
+                .word_list()
+                .get(index)
+                .ok_or_else(|| CliError::InvalidWordIndex {
+                    value: index.to_string(),
+                    position: None,
+                })?;
+        out.section("Word Lookup")?;
+        out.field("Index", index)?;
+        out.field("Language", format_args!("{language:?}"))?;
+        out.blank()?;
+        out.data(word)?;
+        return Ok(());
+    }
+
+    let prefix = prefix.unwrap_or_default();
+    let matches = words_with_prefix(&prefix, bip39_language);
+
+    out.section("Word Lookup")?;
+    out.field("Prefix", format_args!("\"{prefix}\""))?;
+    out.field("Matches", matches.len())?;
+    out.field("Language", format_args!("{language:?}"))?;
+    out.blank()?;
+    for word in &matches {
+        out.data(word)?;
+    }
+    if matches.len() == 1 {
+        out.blank()?;
+        out.line("✓ Prefix is unambiguous")?;
+    }
+
+    Ok(())
+}
+
+pub fn handle_languages(global: &GlobalArgs, quiet: bool) -> Result<(), CliError> {
+    let mut out = Output::stdout(global, quiet);
+    out.section("Supported Languages")?;
+    out.line(format_args!("{:<21} Name", "--language"))?;
+
+    for language in LanguageOption::value_variants() {
+        let value = language
+            .to_possible_value()
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        if quiet {
+            out.data(value)?;
+        } else {
+            out.line(format_args!("{value:<21} {}", language.display_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Hash every embedded word list and compare it with `WORD_LIST_SHA256`
+pub fn handle_integrity(global: &GlobalArgs, quiet: bool) -> Result<(), CliError> {
+    let mismatches = word_list_mismatches(&WORD_LIST_SHA256);
+
+    let mut out = Output::stdout(global, quiet);
+    out.section("Word List Integrity")?;
+    if !quiet {
+        for language in LanguageOption::value_variants() {
+            let bip39_language = bip39::Language::from(*language);
+            let mark = if mismatches.contains(&bip39_language) {
+                "✗"
+            } else {
+                "✓"
+            };
+            out.line(format_args!(
+                "{mark} {:<21} {}",
+                language.display_name(),
+                word_list_sha256(bip39_language)
+            ))?;
+        }
+    }
+
+    if !mismatches.is_empty() {
+        return Err(CliError::WordListMismatch {
+            languages: mismatches
+                .iter()
+                .map(|language| format!("{language:?}"))
+                .collect(),
+        });
+    }
+    if quiet {
+        out.data("ok")?;
+    }
+    Ok(())
+}
+
+/// Print every word of a word list, one per line or as JSON
+pub fn handle_dump(
+    language: LanguageOption,
+    with_index: bool,
+    hex_index: bool,
+    format: DumpFormat,
+    global: &GlobalArgs,
+) -> Result<(), CliError> {
+    // The word list is the whole output, with no headers to drop
+    let mut out = Output::stdout(global, true);
+    let word_list = bip39::Language::from(language).word_list();
+    let format_index = |index: usize| {
+        if hex_index {
+            format!("{index:#05x}")
+        } else {
+            index.to_string()
+        }
+    };
+
+    match format {
+        DumpFormat::Text => {
+            for (index, word) in word_list.iter().enumerate() {
+                if with_index {
+                    out.data(format_args!("{} {word}", format_index(index)))?;
+                } else {
+                    out.data(word)?;
+                }
+            }
+        }
+        DumpFormat::Json => {
+            // Word list entries never contain quotes, backslashes or control characters
+            let entries: Vec<String> = word_list
+                .iter()
+                .enumerate()
+                .map(|(index, word)| match (with_index, hex_index) {
+                    (false, _) => format!("\"{word}\""),
+                    (true, false) => format!("{{\"index\": {index}, \"word\": \"{word}\"}}"),
+                    (true, true) => format!(
+                        "{{\"index\": \"{}\", \"word\": \"{word}\"}}",
+                        format_index(index)
+                    ),
+                })
+                .collect();
+            out.data(format_args!("[\n  {}\n]", entries.join(",\n  ")))?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use bitcoin::bip32::{Xpriv, Xpub};
+use bitcoin::secp256k1::Secp256k1;
+use zeroize::Zeroize;
+
+use crate::cli::{GlobalArgs, NetworkOption};
+use crate::error::CliError;
+use crate::output::Output;
+
+/// Length of a BIP39 seed, the only size `seed-to-xprv` accepts
+const SEED_BYTES: usize = 64;
+
+/// Decode `--seed`, requiring exactly `SEED_BYTES` bytes
+fn decode_seed(seed_hex: &str) -> Result<Vec<u8>, CliError> {
+    let mut seed = hex::decode(seed_hex.trim())?;
+    if seed.len() != SEED_BYTES {
+        let actual = seed.len() * 2;
+        seed.zeroize();
+        return Err(CliError::InvalidHexString {
+            message: format!(
+                "Seed must be {} hex characters, got {actual}",
+                SEED_BYTES * 2
+            ),
+            position: None,
+            hint: format!("A BIP39 seed is {SEED_BYTES} bytes, as printed by 'bip39 seed'"),
+        });
+    }
+    Ok(seed)
+}
+
+pub fn handle_seed_to_xprv(
+    mut seed_hex: String,
+    network: NetworkOption,
+    global: &GlobalArgs,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let seed = decode_seed(&seed_hex);
+    seed_hex.zeroize();
+    let mut seed = seed?;
+
+    let secp = Secp256k1::new();
+    let master = Xpriv::new_master(bitcoin::Network::from(network), &seed);
+    seed.zeroize(); // Clear seed from memory
+    let mut master = master?;
+    let xpub = Xpub::from_priv(&secp, &master);
+    let mut xprv = master.to_string();
+    master.private_key.non_secure_erase(); // Clear master key from memory
+
+    let mut out = Output::stdout(global, quiet);
+    let printed = print_root_key_details(&mut out, network, &xpub, global)
+        .map_err(CliError::from)
+        .and_then(|()| super::emit_secret(&mut out, &xprv, global));
+    xprv.zeroize();
+    printed
+}
+
+fn print_root_key_details(
+    out: &mut Output,
+    network: NetworkOption,
+    xpub: &Xpub,
+    global: &GlobalArgs,
+) -> std::io::Result<()> {
+    out.section("BIP32 Root Key")?;
+    out.field(
+        "Network",
+        format_args!("{:?}", bitcoin::Network::from(network)),
+    )?;
+    out.field(
+        "Fingerprint",
+        global.hex_case.encode(xpub.fingerprint().to_bytes()),
+    )?;
+    out.field("Xpub", xpub)?;
+    out.blank()
+}
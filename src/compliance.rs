@@ -0,0 +1,88 @@
+//! Official BIP39 test vectors, checked at runtime by the `compliance` command
+//!
+//! The vectors are the Trezor `vectors.json` embedded in the binary, so an
+//! installed build can prove it derives the same mnemonics and seeds as the
+//! reference implementation before it is trusted with funds.
+
+use bip39::Mnemonic;
+use serde_json::Value;
+
+/// Passphrase the official vectors use for seed derivation
+pub const VECTOR_PASSPHRASE: &str = "TREZOR";
+
+const VECTORS_JSON: &str = include_str!("../test-vectors.json");
+
+#[derive(Debug, Clone)]
+pub struct TestVector {
+    pub entropy: String,
+    pub mnemonic: String,
+    pub seed: String,
+}
+
+/// Load the English vectors from the embedded `test-vectors.json`
+pub fn load_test_vectors() -> Result<Vec<TestVector>, String> {
+    let data: Value =
+        serde_json::from_str(VECTORS_JSON).map_err(|e| format!("Invalid vectors JSON: {e}"))?;
+
+    let english_vectors = data["english"]
+        .as_array()
+        .ok_or("Missing english vectors")?;
+
+    let mut vectors = Vec::new();
+    for vector in english_vectors {
+        let array = vector.as_array().ok_or("Invalid vector format")?;
+        if array.len() >= 4 {
+            vectors.push(TestVector {
+                entropy: array[0].as_str().unwrap_or("").to_string(),
+                mnemonic: array[1].as_str().unwrap_or("").to_string(),
+                seed: array[2].as_str().unwrap_or("").to_string(),
+            });
+        }
+    }
+
+    Ok(vectors)
+}
+
+/// Check entropy to mnemonic, mnemonic to seed, and the entropy round trip
+pub fn validate_test_vector(vector: &TestVector) -> Result<(), String> {
+    // 1. Test entropy -> mnemonic conversion
+    let entropy_bytes =
+        hex::decode(&vector.entropy).map_err(|e| format!("Failed to decode entropy: {e}"))?;
+
+    let generated_mnemonic = Mnemonic::from_entropy(&entropy_bytes)
+        .map_err(|e| format!("Failed to generate mnemonic from entropy: {e}"))?;
+
+    if generated_mnemonic.to_string() != vector.mnemonic {
+        return Err(format!(
+            "Mnemonic mismatch: expected {}, generated {}",
+            vector.mnemonic, generated_mnemonic
+        ));
+    }
+
+    // 2. Test mnemonic -> seed conversion with the vectors' passphrase
+    let parsed_mnemonic =
+        Mnemonic::parse(&vector.mnemonic).map_err(|e| format!("Failed to parse mnemonic: {e}"))?;
+
+    let generated_seed = parsed_mnemonic.to_seed(VECTOR_PASSPHRASE);
+    let generated_seed_hex = hex::encode(generated_seed);
+
+    if generated_seed_hex != vector.seed {
+        return Err(format!(
+            "Seed mismatch: expected {}, generated {}",
+            vector.seed, generated_seed_hex
+        ));
+    }
+
+    // 3. Test mnemonic -> entropy conversion (round trip)
+    let extracted_entropy = parsed_mnemonic.to_entropy();
+    let extracted_entropy_hex = hex::encode(extracted_entropy);
+
+    if extracted_entropy_hex != vector.entropy {
+        return Err(format!(
+            "Entropy round-trip failed: original {}, extracted {}",
+            vector.entropy, extracted_entropy_hex
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! Key file encryption of seeds for `--encrypt-to` and `decrypt`
+//!
+//! A seed is sealed with ChaCha20-Poly1305 under a key derived by HKDF-SHA256
+//! from the key file's bytes and a random salt. The result is one line of
+//! text, `bip39-seed-v1:` followed by the base64 of salt, nonce and
+//! ciphertext with its tag, so it is stored like any other output file.
+//!
+//! HKDF does not stretch weak input: the key file must hold random bytes
+//! (e.g. `head -c 32 /dev/urandom`), not a password.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use rand::{rngs::OsRng, RngCore};
+use sha2::Sha256;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::error::CliError;
+
+/// Marks an encrypted seed and its format version; also bound in as associated data
+pub const ENCRYPTED_SEED_PREFIX: &str = "bip39-seed-v1:";
+
+/// Fewest bytes a key file may hold: one full ChaCha20 key
+pub const MIN_KEY_FILE_BYTES: usize = 32;
+
+/// Largest key file accepted, matching the other secret inputs
+pub const MAX_KEY_FILE_BYTES: u64 = 4096;
+
+const SALT_BYTES: usize = 16;
+const NONCE_BYTES: usize = 12;
+const HKDF_INFO: &[u8] = b"bip39-cli seed encryption v1";
+
+/// Read the raw bytes of a key file, which must hold at least `MIN_KEY_FILE_BYTES`
+pub fn read_key_file(path: &std::path::Path) -> Result<Zeroizing<Vec<u8>>, CliError> {
+    let file_error = |message: String, hint: &str| CliError::InputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+    let key_hint = "Create a key file of random bytes, e.g. head -c 32 /dev/urandom > seed.key";
+
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| file_error(e.to_string(), "Check that the file exists and is readable"))?;
+    if metadata.len() > MAX_KEY_FILE_BYTES {
+        return Err(file_error(
+            format!(
+                "file is {} bytes (limit {MAX_KEY_FILE_BYTES})",
+                metadata.len()
+            ),
+            key_hint,
+        ));
+    }
+    let key = Zeroizing::new(
+        std::fs::read(path)
+            .map_err(|e| file_error(e.to_string(), "Check that the file is readable"))?,
+    );
+    if key.len() < MIN_KEY_FILE_BYTES {
+        return Err(file_error(
+            format!(
+                "key file holds {} bytes, at least {MIN_KEY_FILE_BYTES} are needed",
+                key.len()
+            ),
+            key_hint,
+        ));
+    }
+    Ok(key)
+}
+
+/// Derive the cipher for one salt from the key file's bytes
+fn cipher_for(key_material: &[u8], salt: &[u8]) -> ChaCha20Poly1305 {
+    let mut key = Key::default();
+    Hkdf::<Sha256>::new(Some(salt), key_material)
+        .expand(HKDF_INFO, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    let cipher = ChaCha20Poly1305::new(&key);
+    key.as_mut_slice().zeroize();
+    cipher
+}
+
+/// Seal `seed` under the key file's bytes, returning the one-line text form
+#[must_use]
+pub fn encrypt_seed(seed: &[u8], key_material: &[u8]) -> String {
+    let mut header = [0u8; SALT_BYTES + NONCE_BYTES];
+    OsRng.fill_bytes(&mut header);
+    let (salt, nonce) = header.split_at(SALT_BYTES);
+
+    let sealed = cipher_for(key_material, salt)
+        .encrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: seed,
+                aad: ENCRYPTED_SEED_PREFIX.as_bytes(),
+            },
+        )
+        .expect("a seed is far below the ChaCha20-Poly1305 message limit");
+
+    let mut blob = header.to_vec();
+    blob.extend_from_slice(&sealed);
+    format!("{ENCRYPTED_SEED_PREFIX}{}", STANDARD.encode(blob))
+}
+
+/// Open the text form written by `encrypt_seed`
+///
+/// A malformed input is reported as an `InputFileError` on `path`; a wrong
+/// key file or a modified input fails authentication with `DecryptionFailed`.
+pub fn decrypt_seed(
+    encoded: &str,
+    key_material: &[u8],
+    path: &std::path::Path,
+) -> Result<Zeroizing<Vec<u8>>, CliError> {
+    let malformed = |message: &str| CliError::InputFileError {
+        path: path.display().to_string(),
+        message: message.to_string(),
+        hint: "The file must be written by 'bip39 seed --encrypt-to'".to_string(),
+    };
+
+    let body = encoded
+        .trim()
+        .strip_prefix(ENCRYPTED_SEED_PREFIX)
+        .ok_or_else(|| malformed("not an encrypted seed (missing bip39-seed-v1: prefix)"))?;
+    let blob = STANDARD
+        .decode(body)
+        .map_err(|_| malformed("encrypted seed is not valid base64"))?;
+    // A sealed seed carries at least its 16-byte tag after the salt and nonce
+    if blob.len() <= SALT_BYTES + NONCE_BYTES + 16 {
+        return Err(malformed("encrypted seed is truncated"));
+    }
+    let (salt, rest) = blob.split_at(SALT_BYTES);
+    let (nonce, sealed) = rest.split_at(NONCE_BYTES);
+
+    cipher_for(key_material, salt)
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: sealed,
+                aad: ENCRYPTED_SEED_PREFIX.as_bytes(),
+            },
+        )
+        .map(Zeroizing::new)
+        .map_err(|_| CliError::DecryptionFailed {
+            message: format!(
+                "{} did not authenticate under this key file",
+                path.display()
+            ),
+        })
+}
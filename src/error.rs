@@ -1,5 +1,7 @@
 use std::fmt;
 
+use serde_json::{json, Value};
+
 #[derive(Debug)]
 pub enum CliError {
     InvalidEntropyLength {
@@ -17,16 +19,298 @@ pub enum CliError {
         expected: Vec<usize>,
         hint: String,
     },
+    UnexpectedWordCount {
+        actual: usize,
+        expected: usize,
+    },
     InvalidWord {
         word: String,
         position: usize,
         suggestions: Vec<String>,
     },
+    InvalidWordIndex {
+        value: String,
+        position: Option<usize>,
+    },
+    InvalidIndexGrid {
+        line: usize,
+        message: String,
+    },
     MnemonicError(bip39::Error),
+    InvalidMnemonic(bip39::Error),
+    ElectrumSeed {
+        seed_type: String,
+    },
+    LanguageDetectionFailed {
+        candidates: Vec<String>,
+    },
     HexDecodeError(hex::FromHexError),
+    DerivationError(bitcoin::bip32::Error),
+    EnvVarNotSet {
+        name: String,
+    },
+    InputFileError {
+        path: String,
+        message: String,
+        hint: String,
+    },
+    OutputFileError {
+        path: String,
+        message: String,
+        hint: String,
+    },
+    SelfTestFailed {
+        mean: f64,
+        threshold: f64,
+    },
+    MnemonicsDiffer {
+        differences: usize,
+    },
+    VerificationMismatch {
+        target: String,
+    },
+    UnsupportedKdfRounds {
+        rounds: u32,
+    },
+    SecureInputError {
+        message: String,
+        hint: String,
+    },
+    WeakPassphrase {
+        score: f64,
+        threshold: f64,
+    },
+    BatchValidationFailed {
+        invalid: usize,
+        total: usize,
+    },
+    WeakEntropy {
+        score: f64,
+        threshold: f64,
+    },
+    AvoidWordsExhausted {
+        attempts: usize,
+        excluded: usize,
+        distinct: bool,
+    },
+    ClipboardError {
+        message: String,
+    },
+    WordListMismatch {
+        languages: Vec<String>,
+    },
+    ComplianceFailed {
+        failed: usize,
+        total: usize,
+    },
+    DecryptionFailed {
+        message: String,
+    },
+    SecretRefused,
+    RepeatedEntropy {
+        draws: usize,
+    },
+    UsageError {
+        message: String,
+        hint: String,
+    },
     NoCommandProvided,
 }
 
+impl CliError {
+    /// Process exit status for this error, stable across releases
+    ///
+    /// | Code | Meaning |
+    /// |------|---------|
+    /// | 1 | Other failure (derivation, unsupported parameters) |
+    /// | 2 | Command-line usage error (also used by argument parsing) |
+    /// | 3 | Invalid word count, or not the word count the caller expected |
+    /// | 4 | Word not in the word list, word index out of range, or malformed index grid |
+    /// | 5 | Invalid hex or entropy length |
+    /// | 6 | Input/output failure (files, environment, terminal, clipboard) |
+    /// | 7 | Mnemonic failed BIP39 validation, or its language could not be detected |
+    /// | 8 | Passphrase or entropy quality below threshold, or repeated entropy under `--unique` |
+    /// | 9 | Mnemonics differ, a mnemonic, word list or test vector does not match its expected value, or an encrypted seed fails authentication |
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::DerivationError(_)
+            | Self::UnsupportedKdfRounds { .. }
+            | Self::AvoidWordsExhausted { .. } => 1,
+            Self::UsageError { .. } | Self::NoCommandProvided => 2,
+            Self::InvalidWordCount { .. } | Self::UnexpectedWordCount { .. } => 3,
+            Self::InvalidWord { .. }
+            | Self::InvalidWordIndex { .. }
+            | Self::InvalidIndexGrid { .. } => 4,
+            Self::InvalidEntropyLength { .. }
+            | Self::InvalidHexString { .. }
+            | Self::HexDecodeError(_) => 5,
+            Self::EnvVarNotSet { .. }
+            | Self::InputFileError { .. }
+            | Self::OutputFileError { .. }
+            | Self::SecureInputError { .. }
+            | Self::ClipboardError { .. }
+            | Self::SecretRefused => 6,
+            Self::MnemonicError(_)
+            | Self::InvalidMnemonic(_)
+            | Self::ElectrumSeed { .. }
+            | Self::LanguageDetectionFailed { .. }
+            | Self::BatchValidationFailed { .. } => 7,
+            Self::WeakPassphrase { .. }
+            | Self::WeakEntropy { .. }
+            | Self::SelfTestFailed { .. }
+            | Self::RepeatedEntropy { .. } => 8,
+            Self::MnemonicsDiffer { .. }
+            | Self::VerificationMismatch { .. }
+            | Self::WordListMismatch { .. }
+            | Self::ComplianceFailed { .. }
+            | Self::DecryptionFailed { .. } => 9,
+        }
+    }
+
+    /// Machine-readable form for `--json-errors`
+    ///
+    /// An object with the variant name as `error_type`, the variant's own
+    /// fields, the `exit_code`, and the human-readable text as `description`.
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let (error_type, fields) = match self {
+            Self::InvalidEntropyLength {
+                actual,
+                expected,
+                hint,
+            } => (
+                "InvalidEntropyLength",
+                json!({ "actual": actual, "expected": expected, "hint": hint }),
+            ),
+            Self::InvalidHexString {
+                message,
+                position,
+                hint,
+            } => (
+                "InvalidHexString",
+                json!({ "message": message, "position": position, "hint": hint }),
+            ),
+            Self::InvalidWordCount {
+                actual,
+                expected,
+                hint,
+            } => (
+                "InvalidWordCount",
+                json!({ "actual": actual, "expected": expected, "hint": hint }),
+            ),
+            Self::UnexpectedWordCount { actual, expected } => (
+                "UnexpectedWordCount",
+                json!({ "actual": actual, "expected": expected }),
+            ),
+            Self::InvalidWord {
+                word,
+                position,
+                suggestions,
+            } => (
+                "InvalidWord",
+                json!({ "word": word, "position": position, "suggestions": suggestions }),
+            ),
+            Self::InvalidWordIndex { value, position } => (
+                "InvalidWordIndex",
+                json!({ "value": value, "position": position }),
+            ),
+            Self::InvalidIndexGrid { line, message } => (
+                "InvalidIndexGrid",
+                json!({ "line": line, "message": message }),
+            ),
+            Self::MnemonicError(e) => ("MnemonicError", json!({ "message": e.to_string() })),
+            Self::InvalidMnemonic(e) => ("InvalidMnemonic", json!({ "message": e.to_string() })),
+            Self::ElectrumSeed { seed_type } => ("ElectrumSeed", json!({ "seed_type": seed_type })),
+            Self::LanguageDetectionFailed { candidates } => (
+                "LanguageDetectionFailed",
+                json!({ "candidates": candidates }),
+            ),
+            Self::HexDecodeError(e) => ("HexDecodeError", json!({ "message": e.to_string() })),
+            Self::DerivationError(e) => ("DerivationError", json!({ "message": e.to_string() })),
+            Self::EnvVarNotSet { name } => ("EnvVarNotSet", json!({ "name": name })),
+            Self::InputFileError {
+                path,
+                message,
+                hint,
+            } => (
+                "InputFileError",
+                json!({ "path": path, "message": message, "hint": hint }),
+            ),
+            Self::OutputFileError {
+                path,
+                message,
+                hint,
+            } => (
+                "OutputFileError",
+                json!({ "path": path, "message": message, "hint": hint }),
+            ),
+            Self::SelfTestFailed { mean, threshold } => (
+                "SelfTestFailed",
+                json!({ "mean": mean, "threshold": threshold }),
+            ),
+            Self::MnemonicsDiffer { differences } => {
+                ("MnemonicsDiffer", json!({ "differences": differences }))
+            }
+            Self::VerificationMismatch { target } => {
+                ("VerificationMismatch", json!({ "target": target }))
+            }
+            Self::UnsupportedKdfRounds { rounds } => {
+                ("UnsupportedKdfRounds", json!({ "rounds": rounds }))
+            }
+            Self::SecureInputError { message, hint } => (
+                "SecureInputError",
+                json!({ "message": message, "hint": hint }),
+            ),
+            Self::WeakPassphrase { score, threshold } => (
+                "WeakPassphrase",
+                json!({ "score": score, "threshold": threshold }),
+            ),
+            Self::BatchValidationFailed { invalid, total } => (
+                "BatchValidationFailed",
+                json!({ "invalid": invalid, "total": total }),
+            ),
+            Self::WeakEntropy { score, threshold } => (
+                "WeakEntropy",
+                json!({ "score": score, "threshold": threshold }),
+            ),
+            Self::AvoidWordsExhausted {
+                attempts,
+                excluded,
+                distinct,
+            } => (
+                "AvoidWordsExhausted",
+                json!({ "attempts": attempts, "excluded": excluded, "distinct": distinct }),
+            ),
+            Self::ClipboardError { message } => ("ClipboardError", json!({ "message": message })),
+            Self::WordListMismatch { languages } => {
+                ("WordListMismatch", json!({ "languages": languages }))
+            }
+            Self::ComplianceFailed { failed, total } => (
+                "ComplianceFailed",
+                json!({ "failed": failed, "total": total }),
+            ),
+            Self::DecryptionFailed { message } => {
+                ("DecryptionFailed", json!({ "message": message }))
+            }
+            Self::SecretRefused => ("SecretRefused", json!({})),
+            Self::RepeatedEntropy { draws } => ("RepeatedEntropy", json!({ "draws": draws })),
+            Self::UsageError { message, hint } => {
+                ("UsageError", json!({ "message": message, "hint": hint }))
+            }
+            Self::NoCommandProvided => ("NoCommandProvided", json!({})),
+        };
+
+        let mut error = json!({ "error_type": error_type });
+        if let (Value::Object(error), Value::Object(fields)) = (&mut error, fields) {
+            error.extend(fields);
+            error.insert("exit_code".to_string(), json!(self.exit_code()));
+            error.insert("description".to_string(), json!(self.to_string()));
+        }
+        error
+    }
+}
+
 impl fmt::Display for CliError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -59,6 +343,16 @@ impl fmt::Display for CliError {
                 writeln!(f, "Expected one of: {expected:?}")?;
                 write!(f, "Hint: {hint}")
             }
+            Self::UnexpectedWordCount { actual, expected } => {
+                writeln!(
+                    f,
+                    "Mnemonic has {actual} words, but {expected} were expected"
+                )?;
+                write!(
+                    f,
+                    "Hint: Check for dropped or extra words; a phrase of the wrong length can still pass the BIP39 checksum"
+                )
+            }
             Self::InvalidWord {
                 word,
                 position,
@@ -73,7 +367,50 @@ impl fmt::Display for CliError {
                     "Hint: Check spelling and ensure the word is from the BIP39 word list"
                 )
             }
+            Self::InvalidWordIndex { value, position } => {
+                writeln!(f, "Invalid word index: {value}")?;
+                if let Some(pos) = position {
+                    writeln!(f, "Error at position: {pos}")?;
+                }
+                write!(f, "Hint: Word indices range from 0 to 2047")
+            }
+            Self::InvalidIndexGrid { line, message } => {
+                writeln!(f, "Invalid index grid at line {line}: {message}")?;
+                write!(
+                    f,
+                    "Hint: Each line holds a word's position (from 1) and its word list index, e.g. \"1 0\""
+                )
+            }
             Self::MnemonicError(e) => write!(f, "BIP39 error: {e}"),
+            Self::InvalidMnemonic(e) => write!(f, "Mnemonic validation failed: {e}"),
+            Self::ElectrumSeed { seed_type } => {
+                writeln!(
+                    f,
+                    "Mnemonic validation failed: this looks like an Electrum {seed_type} seed, not a BIP39 mnemonic"
+                )?;
+                write!(
+                    f,
+                    "Hint: Electrum seeds use their own version scheme; restore it in Electrum or a wallet that supports Electrum seeds"
+                )
+            }
+            Self::LanguageDetectionFailed { candidates } if candidates.is_empty() => {
+                writeln!(
+                    f,
+                    "Could not detect the mnemonic's language: no word list contains every word"
+                )?;
+                write!(
+                    f,
+                    "Hint: Check the spelling, or pass --language to get suggestions for misspelled words"
+                )
+            }
+            Self::LanguageDetectionFailed { candidates } => {
+                writeln!(
+                    f,
+                    "Could not detect the mnemonic's language: it fits {}",
+                    candidates.join(", ")
+                )?;
+                write!(f, "Hint: Pass --language to choose one")
+            }
             Self::HexDecodeError(e) => {
                 writeln!(f, "Hex decode error: {e}")?;
                 write!(
@@ -81,6 +418,169 @@ impl fmt::Display for CliError {
                     "Hint: Ensure the string contains only valid hex characters (0-9, a-f, A-F)"
                 )
             }
+            Self::DerivationError(e) => write!(f, "BIP32 derivation error: {e}"),
+            Self::EnvVarNotSet { name } => {
+                writeln!(f, "Environment variable '{name}' is not set")?;
+                write!(
+                    f,
+                    "Hint: Export the variable before running, e.g. {name}='...' bip39 ..."
+                )
+            }
+            Self::InputFileError {
+                path,
+                message,
+                hint,
+            } => {
+                writeln!(f, "Cannot read input file '{path}': {message}")?;
+                write!(f, "Hint: {hint}")
+            }
+            Self::OutputFileError {
+                path,
+                message,
+                hint,
+            } => {
+                writeln!(f, "Cannot write output file '{path}': {message}")?;
+                write!(f, "Hint: {hint}")
+            }
+            Self::SelfTestFailed { mean, threshold } => {
+                writeln!(
+                    f,
+                    "Entropy self-test failed: mean score {mean:.3} is below {threshold:.2}"
+                )?;
+                write!(
+                    f,
+                    "Hint: OsRng output should score near 1.0; either the system RNG or the quality heuristics are broken"
+                )
+            }
+            Self::MnemonicsDiffer { differences } => {
+                write!(f, "Mnemonics differ at {differences} position(s)")
+            }
+            Self::WordListMismatch { languages } => {
+                writeln!(
+                    f,
+                    "Embedded word list does not match its known digest: {}",
+                    languages.join(", ")
+                )?;
+                write!(
+                    f,
+                    "Hint: This binary may be corrupted or tampered with; reinstall it from a trusted source"
+                )
+            }
+            Self::ComplianceFailed { failed, total } => {
+                writeln!(f, "{failed} of {total} BIP39 test vectors failed")?;
+                write!(
+                    f,
+                    "Hint: This build does not derive standard mnemonics or seeds; do not use it with funds"
+                )
+            }
+            Self::DecryptionFailed { message } => {
+                writeln!(f, "Decryption failed: {message}")?;
+                write!(
+                    f,
+                    "Hint: Use the key file the seed was encrypted with; the encrypted file may also have been modified"
+                )
+            }
+            Self::RepeatedEntropy { draws } => {
+                writeln!(
+                    f,
+                    "The RNG repeated an earlier mnemonic {draws} times in a row"
+                )?;
+                write!(
+                    f,
+                    "Hint: The system RNG is not producing random output; discard every mnemonic from this run"
+                )
+            }
+            Self::VerificationMismatch { target } => {
+                writeln!(f, "Mnemonic does not reproduce the expected {target}")?;
+                write!(
+                    f,
+                    "Hint: Check the words, their order, the language and the passphrase"
+                )
+            }
+            Self::UnsupportedKdfRounds { rounds } => {
+                writeln!(
+                    f,
+                    "Unsupported KDF rounds: {rounds} (BIP39 fixes PBKDF2-HMAC-SHA512 at exactly 2048 rounds)"
+                )?;
+                writeln!(
+                    f,
+                    "A seed derived with any other count is not a BIP39 seed and other wallets will not reproduce it"
+                )?;
+                write!(
+                    f,
+                    "Hint: For custom key derivation, derive BIP32 child keys from the standard seed (see 'bip39 address')"
+                )
+            }
+            Self::SecureInputError { message, hint } => {
+                writeln!(f, "{message}")?;
+                write!(f, "Hint: {hint}")
+            }
+            Self::WeakPassphrase { score, threshold } => {
+                writeln!(
+                    f,
+                    "Passphrase strength too low: score {score:.2} is below the required {threshold:.2}"
+                )?;
+                write!(
+                    f,
+                    "Hint: Use a longer, more complex passphrase, lower --min-passphrase-score, or pass --no-passphrase-check"
+                )
+            }
+            Self::BatchValidationFailed { invalid, total } => {
+                write!(f, "{invalid} of {total} mnemonics failed validation")
+            }
+            Self::WeakEntropy { score, threshold } => {
+                writeln!(
+                    f,
+                    "Entropy quality too low: score {score:.2} is below the required {threshold:.2}"
+                )?;
+                write!(
+                    f,
+                    "Hint: Run 'bip39 analyze-entropy' for details and regenerate the entropy with a cryptographically secure source"
+                )
+            }
+            Self::AvoidWordsExhausted {
+                attempts,
+                excluded,
+                distinct,
+            } => {
+                let wanted = match (excluded, distinct) {
+                    (0, _) => "with all words distinct".to_string(),
+                    (_, false) => format!("without the {excluded} excluded word(s)"),
+                    (_, true) => format!(
+                        "without the {excluded} excluded word(s) and with all words distinct"
+                    ),
+                };
+                writeln!(f, "No mnemonic {wanted} found after {attempts} attempts")?;
+                if *distinct {
+                    write!(
+                        f,
+                        "Hint: Exclude fewer words with --avoid-word, or drop --no-repeats"
+                    )
+                } else {
+                    write!(f, "Hint: Exclude fewer words with --avoid-word")
+                }
+            }
+            Self::ClipboardError { message } => {
+                writeln!(f, "Clipboard unavailable: {message}")?;
+                write!(
+                    f,
+                    "Hint: --copy needs a desktop session with a clipboard; on headless systems omit --copy"
+                )
+            }
+            Self::SecretRefused => {
+                writeln!(
+                    f,
+                    "Refusing to print a secret: the output is not a terminal (--require-tty)"
+                )?;
+                write!(
+                    f,
+                    "Hint: Write it to a file with --output-file (or --out-fd/--copy for generate), or drop --require-tty"
+                )
+            }
+            Self::UsageError { message, hint } => {
+                writeln!(f, "{message}")?;
+                write!(f, "Hint: {hint}")
+            }
             Self::NoCommandProvided => {
                 write!(f, "No command provided. Use --help for usage information.")
             }
@@ -102,11 +602,22 @@ impl From<hex::FromHexError> for CliError {
     }
 }
 
+impl From<bitcoin::bip32::Error> for CliError {
+    fn from(error: bitcoin::bip32::Error) -> Self {
+        Self::DerivationError(error)
+    }
+}
+
 impl From<std::io::Error> for CliError {
     fn from(error: std::io::Error) -> Self {
-        Self::InvalidHexString {
+        if error
+            .get_ref()
+            .is_some_and(|inner| inner.is::<crate::output::SecretRefused>())
+        {
+            return Self::SecretRefused;
+        }
+        Self::SecureInputError {
             message: format!("IO error: {error}"),
-            position: None,
             hint: "Check terminal permissions and capabilities".to_string(),
         }
     }
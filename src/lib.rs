@@ -1,6 +1,11 @@
 pub mod cli;
+pub mod clipboard;
 pub mod commands;
+pub mod compliance;
+pub mod encryption;
 pub mod error;
+pub mod memory;
+pub mod output;
 pub mod security;
 
 pub use cli::Cli;
@@ -9,19 +14,33 @@ pub use error::CliError;
 use clap::{CommandFactory, Parser};
 
 pub fn run() -> Result<(), CliError> {
-    let cli = Cli::parse();
+    run_cli(Cli::parse())
+}
 
+/// Run already-parsed arguments, so the caller can read global flags first
+pub fn run_cli(cli: Cli) -> Result<(), CliError> {
     // Handle security check flag
     if cli.security_check {
-        security::show_security_warnings().map_err(|e| CliError::InvalidHexString {
+        security::show_security_warnings().map_err(|e| CliError::SecureInputError {
             message: format!("Failed to display security warnings: {e}"),
-            position: None,
             hint: "Terminal may not support colored output".to_string(),
         })?;
 
-        let air_gap_status = security::check_air_gapped_environment();
+        let air_gap_status = if cli.deep {
+            security::check_air_gapped_environment_deep(security::probe_connectivity)
+        } else {
+            security::check_air_gapped_environment()
+        };
         println!("\n🔍 Air-Gap Environment Check:");
         println!("Score: {:.1}/1.0", air_gap_status.score);
+        match air_gap_status.connectivity {
+            security::Connectivity::NotProbed => {}
+            security::Connectivity::NoInterfaces => println!("Connectivity: no network interfaces"),
+            security::Connectivity::InterfacesOffline => {
+                println!("Connectivity: interfaces present but no connectivity");
+            }
+            security::Connectivity::Online => println!("Connectivity: online"),
+        }
 
         if air_gap_status.is_air_gapped {
             println!("✅ Environment appears to be air-gapped");
@@ -43,22 +62,45 @@ pub fn run() -> Result<(), CliError> {
     if let Some(command) = cli.command {
         // Show security warnings by default unless in quiet mode
         let is_quiet = match &command {
-            cli::Commands::Generate { quiet, .. } => *quiet,
+            cli::Commands::Generate {
+                quiet,
+                seed_only,
+                raw,
+                ..
+            } => *quiet || *seed_only || *raw,
             cli::Commands::Validate { quiet, .. } => *quiet,
             cli::Commands::Seed { quiet, .. } => *quiet,
+            cli::Commands::Decrypt { quiet, .. } => *quiet,
             cli::Commands::FromEntropy { quiet, .. } => *quiet,
-            cli::Commands::Entropy { quiet, .. } => *quiet,
+            cli::Commands::Entropy { quiet, raw, .. } => *quiet || *raw,
+            cli::Commands::Address { quiet, .. } => *quiet,
+            cli::Commands::AnalyzePassphrase { quiet, .. } => *quiet,
+            cli::Commands::AnalyzeEntropy { quiet, .. } => *quiet,
+            cli::Commands::SelfTest { quiet, .. } => *quiet,
+            cli::Commands::Dump { .. } => true,
+            cli::Commands::Words { quiet, .. } => *quiet,
+            cli::Commands::Languages { quiet } => *quiet,
+            cli::Commands::Integrity { quiet } => *quiet,
+            cli::Commands::Compliance { quiet } => *quiet,
+            cli::Commands::Bench { quiet, .. } => *quiet,
+            cli::Commands::FromIndices { quiet, .. } => *quiet,
+            cli::Commands::FromImage { quiet, .. } => *quiet,
+            cli::Commands::Fingerprint { quiet, .. } => *quiet,
+            cli::Commands::SeedToXprv { quiet, .. } => *quiet,
+            cli::Commands::Verify { quiet, .. } => *quiet,
+            cli::Commands::Info { quiet, .. } => *quiet,
+            cli::Commands::Strength { quiet, .. } => *quiet,
+            cli::Commands::Diff { quiet, .. } => *quiet,
         };
 
         if !is_quiet {
-            security::show_security_warnings().map_err(|e| CliError::InvalidHexString {
+            security::show_security_warnings().map_err(|e| CliError::SecureInputError {
                 message: format!("Failed to display security warnings: {e}"),
-                position: None,
                 hint: "Terminal may not support colored output".to_string(),
             })?;
         }
 
-        commands::run_command(command)?;
+        commands::run_command(command, &cli.global)?;
     } else {
         return Err(CliError::NoCommandProvided);
     }
@@ -1,8 +1,15 @@
-use bip39_cli::run;
+use bip39_cli::{run_cli, Cli};
+use clap::Parser;
 
 fn main() {
-    if let Err(e) = run() {
-        eprintln!("Error: {e}");
-        std::process::exit(1);
+    let cli = Cli::parse();
+    let json_errors = cli.global.json_errors;
+    if let Err(e) = run_cli(cli) {
+        if json_errors {
+            eprintln!("{}", e.to_json());
+        } else {
+            eprintln!("Error: {e}");
+        }
+        std::process::exit(e.exit_code());
     }
 }
@@ -0,0 +1,207 @@
+//! Secret byte buffers that can be locked into RAM with `--memory-lock`
+//!
+//! Zeroizing clears a secret once it is no longer needed, but until then the
+//! kernel may page it out to swap. A locked buffer pins its pages with
+//! `mlock` for its whole lifetime, so entropy, seeds and passphrases never
+//! reach disk.
+//!
+//! `mlock` works on whole pages and does not nest: unlocking any byte of a page
+//! unlocks all of it. Each buffer therefore owns its own page-aligned
+//! allocation, so dropping one secret can never unlock another's pages.
+
+use std::alloc::{self, Layout};
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use zeroize::Zeroize;
+
+/// Set once the "could not lock" warning has been printed
+static LOCK_WARNING_SHOWN: AtomicBool = AtomicBool::new(false);
+
+/// A fixed-size secret buffer, zeroized on drop and optionally locked into RAM
+pub struct SecretBuffer {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Layout,
+    locked: bool,
+}
+
+// SAFETY: the buffer uniquely owns its allocation, like a `Box<[u8]>`
+unsafe impl Send for SecretBuffer {}
+// SAFETY: shared access only hands out `&[u8]`
+unsafe impl Sync for SecretBuffer {}
+
+impl SecretBuffer {
+    /// A zeroed buffer of `len` bytes, locked into RAM when `lock` is set
+    ///
+    /// If the pages cannot be locked (e.g. `RLIMIT_MEMLOCK` is too low, or the
+    /// platform has no `mlock`) a warning is printed once and the buffer is
+    /// used unlocked.
+    #[must_use]
+    pub fn new(len: usize, lock: bool) -> Self {
+        let page = page_size();
+        let size = len.max(1).div_ceil(page) * page;
+        let layout = Layout::from_size_align(size, page).expect("page-sized layout is valid");
+        // SAFETY: `layout` has a non-zero size
+        let raw = unsafe { alloc::alloc_zeroed(layout) };
+        let Some(ptr) = NonNull::new(raw) else {
+            alloc::handle_alloc_error(layout);
+        };
+
+        let locked = lock
+            && match lock_pages(ptr, size) {
+                Ok(()) => true,
+                Err(e) => {
+                    if !LOCK_WARNING_SHOWN.swap(true, Ordering::Relaxed) {
+                        eprintln!(
+                            "⚠️  Warning: could not lock secret memory ({e}); it may be swapped to disk"
+                        );
+                    }
+                    false
+                }
+            };
+        Self {
+            ptr,
+            len,
+            layout,
+            locked,
+        }
+    }
+
+    /// A buffer holding a copy of `bytes`; the caller still zeroizes the original
+    #[must_use]
+    pub fn from_slice(bytes: &[u8], lock: bool) -> Self {
+        let mut buffer = Self::new(bytes.len(), lock);
+        buffer.copy_from_slice(bytes);
+        buffer
+    }
+
+    /// Whether the buffer's pages are locked into RAM
+    #[must_use]
+    pub const fn is_locked(&self) -> bool {
+        self.locked
+    }
+}
+
+impl Deref for SecretBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` points to at least `len` initialized bytes owned by `self`
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for SecretBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: as in `deref`, and `&mut self` guarantees exclusive access
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for SecretBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Drop for SecretBuffer {
+    fn drop(&mut self) {
+        let size = self.layout.size();
+        // Cleared in place while still locked, so the secret is never paged out
+        // SAFETY: the whole allocation is owned by `self` and initialized
+        unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), size) }.zeroize();
+        if self.locked {
+            // Nothing useful can be done if unlocking fails; exit releases the pages
+            let _ = unlock_pages(self.ptr, size);
+        }
+        // SAFETY: allocated in `new` with this same layout
+        unsafe { alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+/// A passphrase held in a [`SecretBuffer`], zeroized on drop
+pub struct SecretString(SecretBuffer);
+
+impl SecretString {
+    /// Moves `value` into a (possibly locked) buffer and zeroizes the original
+    #[must_use]
+    pub fn from_string(mut value: String, lock: bool) -> Self {
+        let buffer = SecretBuffer::from_slice(value.as_bytes(), lock);
+        value.zeroize();
+        Self(buffer)
+    }
+
+    /// Whether the passphrase's pages are locked into RAM
+    #[must_use]
+    pub const fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+}
+
+impl Deref for SecretString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        // SAFETY: the bytes were copied from a `String` and are never mutated
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl AsRef<str> for SecretString {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(unix)]
+fn page_size() -> usize {
+    // SAFETY: sysconf has no preconditions
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    usize::try_from(size)
+        .ok()
+        .filter(|&s| s > 0)
+        .unwrap_or(4096)
+}
+
+#[cfg(not(unix))]
+fn page_size() -> usize {
+    4096
+}
+
+#[cfg(unix)]
+fn lock_pages(ptr: NonNull<u8>, len: usize) -> io::Result<()> {
+    // SAFETY: the pointer and length describe a live allocation owned by the buffer
+    let result = unsafe { libc::mlock(ptr.as_ptr().cast(), len) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(unix)]
+fn unlock_pages(ptr: NonNull<u8>, len: usize) -> io::Result<()> {
+    // SAFETY: same allocation that `lock_pages` locked; it is still alive
+    let result = unsafe { libc::munlock(ptr.as_ptr().cast(), len) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(unix))]
+fn lock_pages(_ptr: NonNull<u8>, _len: usize) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "memory locking is only supported on Unix",
+    ))
+}
+
+#[cfg(not(unix))]
+fn unlock_pages(_ptr: NonNull<u8>, _len: usize) -> io::Result<()> {
+    Ok(())
+}
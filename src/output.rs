@@ -0,0 +1,156 @@
+//! Text output shared by the command handlers
+//!
+//! Handlers say what they print (sections, labeled fields, the result) and
+//! `Output` decides how: headers are dropped with `--quiet`, and the quiet
+//! result is framed by `--output-prefix` and `--output-suffix`. With
+//! `--require-tty`, writing secret material to a stdout that is not a
+//! terminal fails instead.
+
+use std::fmt::Display;
+use std::io::{self, Stdout, Write};
+
+use console::{measure_text_width, Term};
+
+use crate::cli::GlobalArgs;
+
+/// Why a secret write was refused; `CliError` recognizes it inside an `io::Error`
+#[derive(Debug)]
+pub struct SecretRefused;
+
+impl std::fmt::Display for SecretRefused {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "stdout is not a terminal")
+    }
+}
+
+impl std::error::Error for SecretRefused {}
+
+/// Destination and mode of a command's standard output
+pub struct Output<W: Write = Stdout> {
+    writer: W,
+    quiet: bool,
+    prefix: String,
+    suffix: String,
+    refuse_secrets: bool,
+}
+
+impl Output {
+    /// Stdout for a command, honoring its `--quiet` flag and the global framing options
+    #[must_use]
+    pub fn stdout(global: &GlobalArgs, quiet: bool) -> Self {
+        Self::new(io::stdout(), quiet)
+            .framed(
+                global.output_prefix.as_deref().unwrap_or_default(),
+                global.output_suffix.as_deref().unwrap_or_default(),
+            )
+            .refusing_secrets(global.require_tty && !Term::stdout().is_term())
+    }
+}
+
+impl<W: Write> Output<W> {
+    #[must_use]
+    pub fn new(writer: W, quiet: bool) -> Self {
+        Self {
+            writer,
+            quiet,
+            prefix: String::new(),
+            suffix: String::new(),
+            refuse_secrets: false,
+        }
+    }
+
+    /// Wrap quiet results in `prefix` and `suffix`
+    #[must_use]
+    pub fn framed(mut self, prefix: &str, suffix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Fail every write of secret material with `SecretRefused` when `refuse` is set
+    #[must_use]
+    pub const fn refusing_secrets(mut self, refuse: bool) -> Self {
+        self.refuse_secrets = refuse;
+        self
+    }
+
+    fn check_secret(&self) -> io::Result<()> {
+        if self.refuse_secrets {
+            return Err(io::Error::other(SecretRefused));
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub const fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Title underlined with a rule as wide as the title is on screen
+    pub fn section(&mut self, title: &str) -> io::Result<()> {
+        self.section_with_rule(title, measure_text_width(title))
+    }
+
+    /// Title underlined with a rule of `width` characters
+    ///
+    /// A few long-standing headers have a rule one wider than their title;
+    /// they use this so existing output stays byte-for-byte the same.
+    pub fn section_with_rule(&mut self, title: &str, width: usize) -> io::Result<()> {
+        if self.quiet {
+            return Ok(());
+        }
+        writeln!(self.writer, "{title}")?;
+        writeln!(self.writer, "{}", "═".repeat(width))
+    }
+
+    /// A `key: value` line
+    pub fn field(&mut self, key: &str, value: impl Display) -> io::Result<()> {
+        self.line(format_args!("{key}: {value}"))
+    }
+
+    /// A free-form line of the report
+    pub fn line(&mut self, text: impl Display) -> io::Result<()> {
+        if self.quiet {
+            return Ok(());
+        }
+        writeln!(self.writer, "{text}")
+    }
+
+    /// An empty line between report blocks
+    pub fn blank(&mut self) -> io::Result<()> {
+        self.line("")
+    }
+
+    /// The primary result, framed in quiet mode
+    pub fn secret(&mut self, value: &str) -> io::Result<()> {
+        self.check_secret()?;
+        if self.quiet {
+            writeln!(self.writer, "{}{value}{}", self.prefix, self.suffix)
+        } else {
+            writeln!(self.writer, "{value}")
+        }
+    }
+
+    /// A line written in both modes and never framed, e.g. one result of a batch
+    pub fn data(&mut self, text: impl Display) -> io::Result<()> {
+        writeln!(self.writer, "{text}")
+    }
+
+    /// A line holding secret material (words, entropy, a seed), written in both modes and never framed
+    pub fn secret_line(&mut self, text: impl Display) -> io::Result<()> {
+        self.check_secret()?;
+        writeln!(self.writer, "{text}")
+    }
+
+    /// Bytes exactly as given, with no encoding and no trailing newline
+    pub fn raw(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.check_secret()?;
+        self.writer.write_all(bytes)?;
+        self.writer.flush()
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
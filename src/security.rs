@@ -1,4 +1,10 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
 use console::{Style, Term};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha512};
+use unicode_normalization::UnicodeNormalization;
 use zeroize::Zeroize;
 
 /// Securely clear sensitive data from memory
@@ -7,14 +13,15 @@ pub fn clear_sensitive_data<T: Zeroize>(mut data: T) -> T {
     data
 }
 
+/// Valid BIP39 entropy lengths in hex characters (16, 20, 24, 28 and 32 bytes)
+pub const ENTROPY_HEX_LENGTHS: [usize; 5] = [32, 40, 48, 56, 64];
+
 /// Validate entropy hex string with detailed error reporting
 pub fn validate_entropy_hex(hex_str: &str) -> Result<(), crate::error::CliError> {
-    let expected_lengths = vec![32, 40, 48, 56, 64]; // 16, 20, 24, 28, 32 bytes
-
-    if !expected_lengths.contains(&hex_str.len()) {
+    if !ENTROPY_HEX_LENGTHS.contains(&hex_str.len()) {
         return Err(crate::error::CliError::InvalidEntropyLength {
             actual: hex_str.len(),
-            expected: expected_lengths,
+            expected: ENTROPY_HEX_LENGTHS.to_vec(),
             hint: format!(
                 "For {} words, use {} hex characters",
                 match hex_str.len() {
@@ -53,6 +60,94 @@ pub fn validate_entropy_hex(hex_str: &str) -> Result<(), crate::error::CliError>
     Ok(())
 }
 
+/// Whether a word list uses Latin script (and therefore lowercase ASCII-ish words)
+#[must_use]
+pub fn is_latin_script(language: bip39::Language) -> bool {
+    matches!(
+        language,
+        bip39::Language::English
+            | bip39::Language::Spanish
+            | bip39::Language::French
+            | bip39::Language::Italian
+            | bip39::Language::Czech
+            | bip39::Language::Portuguese
+    )
+}
+
+/// Canonicalize a mnemonic into the exact form that is fed to the parser:
+/// NFKD normalization, case folding for Latin-script word lists, stripping of
+/// list decorations (numbering, commas, quotes) and whitespace collapsing
+#[must_use]
+pub fn normalize_mnemonic(input: &str, language: bip39::Language) -> String {
+    let mut decomposed: String = input.nfkd().collect();
+    if is_latin_script(language) {
+        let lowered = decomposed.to_lowercase();
+        decomposed.zeroize();
+        decomposed = lowered;
+    }
+
+    let normalized = decomposed
+        .split_whitespace()
+        .map(strip_decorations)
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    decomposed.zeroize();
+
+    normalized
+}
+
+/// Strip list numbering (`1.`, `02)`) and surrounding punctuation from a word
+fn strip_decorations(word: &str) -> &str {
+    word.trim_start_matches(|c: char| c.is_ascii_digit() || c.is_ascii_punctuation())
+        .trim_end_matches(|c: char| c.is_ascii_punctuation())
+}
+
+/// A character of pasted input that may not be what it looks like
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuspiciousChar {
+    /// 1-based position among the input's characters
+    pub position: usize,
+    pub character: char,
+    pub reason: &'static str,
+}
+
+/// Find characters a reader cannot see or tell apart from the expected ones:
+/// invisible and zero-width characters, unusual spaces, compatibility forms
+/// that NFKD rewrites (fullwidth letters, ligatures), and non-ASCII look-alikes
+/// when every word of `language` is ASCII
+#[must_use]
+pub fn find_suspicious_characters(input: &str, language: bip39::Language) -> Vec<SuspiciousChar> {
+    let ascii_list = language.word_list().iter().all(|word| word.is_ascii());
+    input
+        .chars()
+        .enumerate()
+        .filter_map(|(index, character)| {
+            let reason = match character {
+                ' ' | '\t' | '\n' | '\r' => return None,
+                '\u{00AD}'
+                | '\u{034F}'
+                | '\u{180E}'
+                | '\u{200B}'..='\u{200F}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{FEFF}' => "invisible character",
+                c if c.is_whitespace() => "non-ASCII space, read as a space",
+                c if c.is_control() => "control character",
+                c if c.nfd().ne(c.nfkd()) => "compatibility form, rewritten by NFKD",
+                c if ascii_list && !c.is_ascii() => {
+                    "non-ASCII look-alike; every word in this list is ASCII"
+                }
+                _ => return None,
+            };
+            Some(SuspiciousChar {
+                position: index + 1,
+                character,
+                reason,
+            })
+        })
+        .collect()
+}
+
 /// Validate mnemonic word count with helpful suggestions
 pub fn validate_mnemonic_word_count(mnemonic: &str) -> Result<(), crate::error::CliError> {
     let word_count = mnemonic.split_whitespace().count();
@@ -76,27 +171,280 @@ pub fn validate_mnemonic_word_count(mnemonic: &str) -> Result<(), crate::error::
     Ok(())
 }
 
-/// Find invalid words in a mnemonic with suggestions
+/// Pack the 11-bit word indices of a normalized mnemonic into a bit buffer, with the word count
+fn pack_word_bits(
+    mnemonic: &str,
+    language: bip39::Language,
+) -> Result<([u8; 33], usize), bip39::Error> {
+    let word_count = mnemonic.split_whitespace().count();
+    if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+        return Err(bip39::Error::BadWordCount(word_count));
+    }
+
+    // 24 words * 11 bits = 264 bits = 33 bytes
+    let mut bits = [0u8; 33];
+    let mut offset = 0;
+    for (i, word) in mnemonic.split_whitespace().enumerate() {
+        let Some(index) = language.find_word(word) else {
+            bits.zeroize();
+            return Err(bip39::Error::UnknownWord(i));
+        };
+        for shift in (0..11).rev() {
+            if (index >> shift) & 1 == 1 {
+                bits[offset / 8] |= 0x80 >> (offset % 8);
+            }
+            offset += 1;
+        }
+    }
+
+    Ok((bits, word_count))
+}
+
+/// BIP39 checksum of `entropy`: the first `entropy bits / 32` bits of its SHA-256 hash
+#[must_use]
+pub fn entropy_checksum(entropy: &[u8]) -> u8 {
+    let checksum_bits = entropy.len() / 4;
+    Sha256::digest(entropy)[0] >> (8 - checksum_bits)
+}
+
+/// Verify word membership and checksum bits of a normalized mnemonic
+///
+/// Cheaper than `Mnemonic::parse_in_normalized` for bulk checks: the words are
+/// packed straight into a byte buffer and no `Mnemonic` or entropy is produced.
+pub fn verify_checksum(mnemonic: &str, language: bip39::Language) -> Result<(), bip39::Error> {
+    let (mut bits, word_count) = pack_word_bits(mnemonic, language)?;
+
+    let entropy_len = word_count / 3 * 4;
+    let checksum_bits = word_count / 3;
+    let expected = entropy_checksum(&bits[..entropy_len]);
+    let actual = bits[entropy_len] >> (8 - checksum_bits);
+    bits.zeroize();
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(bip39::Error::InvalidChecksum)
+    }
+}
+
+/// Split a normalized mnemonic into its entropy bytes and checksum bits without verifying them
+pub fn split_entropy_checksum(
+    mnemonic: &str,
+    language: bip39::Language,
+) -> Result<(Vec<u8>, u8), bip39::Error> {
+    let (mut bits, word_count) = pack_word_bits(mnemonic, language)?;
+
+    let entropy_len = word_count / 3 * 4;
+    let entropy = bits[..entropy_len].to_vec();
+    let checksum = bits[entropy_len] >> (8 - word_count / 3);
+    bits.zeroize();
+
+    Ok((entropy, checksum))
+}
+
+/// Words of a normalized mnemonic that occur at least `min_count` times, with their counts
+///
+/// Listed in order of first appearance.
+#[must_use]
+pub fn repeated_words(mnemonic: &str, min_count: usize) -> Vec<(&str, usize)> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for word in mnemonic.split_whitespace() {
+        match counts.iter_mut().find(|(seen, _)| *seen == word) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((word, 1)),
+        }
+    }
+    counts.retain(|&(_, count)| count >= min_count);
+    counts
+}
+
+/// Rolls of a six-sided die that can encode `bytes` of entropy: the smallest n with 6^n >= 2^(8 * bytes)
+#[must_use]
+pub fn dice_roll_count(bytes: usize) -> usize {
+    (bytes as f64 * 8.0 / 6f64.log2()).ceil() as usize
+}
+
+/// Express entropy as dice rolls (1-6), most significant first
+///
+/// The entropy is read as one big-endian number and written in base 6, roll
+/// r standing for digit r - 1. The result is padded with leading 1s to
+/// `dice_roll_count` rolls, so every entropy of a given size gives the same
+/// number of rolls and reading them back reproduces it exactly.
+#[must_use]
+pub fn entropy_to_dice(entropy: &[u8]) -> Vec<u8> {
+    let mut value = entropy.to_vec();
+    let mut rolls = Vec::with_capacity(dice_roll_count(entropy.len()));
+    for _ in 0..dice_roll_count(entropy.len()) {
+        // Long division of the whole number by 6; the remainder is the next digit
+        let mut remainder = 0u16;
+        for byte in &mut value {
+            let current = (remainder << 8) | u16::from(*byte);
+            *byte = (current / 6) as u8;
+            remainder = current % 6;
+        }
+        rolls.push(remainder as u8 + 1);
+    }
+    value.zeroize();
+    rolls.reverse();
+    rolls
+}
+
+/// Inverse of `entropy_to_dice`: read rolls (1-6) as a base-6 number of `bytes` bytes
+///
+/// Returns `None` if a roll is outside 1-6 or the value does not fit in `bytes`.
+#[must_use]
+pub fn dice_to_entropy(rolls: &[u8], bytes: usize) -> Option<Vec<u8>> {
+    let mut value = vec![0u8; bytes];
+    for &roll in rolls {
+        if !(1..=6).contains(&roll) {
+            value.zeroize();
+            return None;
+        }
+        // value = value * 6 + digit, carried from the least significant byte
+        let mut carry = u16::from(roll - 1);
+        for byte in value.iter_mut().rev() {
+            let current = u16::from(*byte) * 6 + carry;
+            *byte = (current & 0xff) as u8;
+            carry = current >> 8;
+        }
+        if carry != 0 {
+            value.zeroize();
+            return None;
+        }
+    }
+    Some(value)
+}
+
+/// Detect an Electrum seed by its version prefix, returning the seed type
+///
+/// Electrum seeds reuse the BIP39 English word list but are versioned by
+/// `HMAC-SHA512("Seed version", phrase)` instead of a BIP39 checksum.
+#[must_use]
+pub fn detect_electrum_seed(mnemonic: &str) -> Option<&'static str> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"Seed version").ok()?;
+    mac.update(mnemonic.as_bytes());
+    let version = hex::encode(&mac.finalize().into_bytes()[..2]);
+
+    [
+        ("01", "standard"),
+        ("100", "segwit"),
+        ("101", "2FA"),
+        ("102", "2FA segwit"),
+    ]
+    .iter()
+    .find(|(prefix, _)| version.starts_with(prefix))
+    .map(|(_, seed_type)| *seed_type)
+}
+
+/// Salted commitment to a mnemonic: `HMAC-SHA256(key = salt, message = phrase)`
+///
+/// The phrase is hashed exactly as printed (single spaces, NFKD), so anyone
+/// holding the salt and the phrase can recompute the commitment, while the
+/// commitment alone reveals nothing about the phrase.
+#[must_use]
+pub fn mnemonic_commitment(salt: &[u8], mnemonic: &str) -> [u8; 32] {
+    // HMAC accepts keys of any length, so this cannot fail
+    let mut mac = Hmac::<Sha256>::new_from_slice(salt).expect("HMAC takes keys of any length");
+    mac.update(mnemonic.as_bytes());
+    mac.finalize().into_bytes().into()
+}
+
+/// Number of suggestions offered per invalid word unless `--suggestions` says otherwise
+pub const DEFAULT_SUGGESTIONS: usize = 3;
+
+/// Largest edit distance a suggestion may have unless `--max-edit-distance` says otherwise
+pub const DEFAULT_MAX_EDIT_DISTANCE: usize = 2;
+
+/// Detect the word list of a mnemonic for `--language auto`
+///
+/// A language is a candidate when its word list holds every word; if several
+/// are, the BIP39 checksum picks between them. Anything other than exactly one
+/// match fails with the remaining candidates.
+pub fn detect_language(mnemonic: &str) -> Result<bip39::Language, crate::error::CliError> {
+    let parses_in = |language: bip39::Language, checksum: bool| {
+        let mut normalized = normalize_mnemonic(mnemonic, language);
+        let found = if checksum {
+            bip39::Mnemonic::parse_in_normalized(language, &normalized).is_ok()
+        } else {
+            !normalized.is_empty()
+                && normalized
+                    .split_whitespace()
+                    .all(|word| language.find_word(word).is_some())
+        };
+        normalized.zeroize();
+        found
+    };
+
+    let mut candidates: Vec<bip39::Language> = bip39::Language::ALL
+        .iter()
+        .copied()
+        .filter(|&language| parses_in(language, false))
+        .collect();
+    if candidates.len() > 1 {
+        let valid: Vec<bip39::Language> = candidates
+            .iter()
+            .copied()
+            .filter(|&language| parses_in(language, true))
+            .collect();
+        if !valid.is_empty() {
+            candidates = valid;
+        }
+    }
+
+    match candidates[..] {
+        [language] => Ok(language),
+        _ => Err(crate::error::CliError::LanguageDetectionFailed {
+            candidates: candidates
+                .iter()
+                .map(|language| format!("{language:?}"))
+                .collect(),
+        }),
+    }
+}
+
+/// Reject the first word of a normalized mnemonic that is not in the word list
+///
+/// Positions count words of the normalized form from 1, so every command
+/// reports a typo the same way however the input was spaced or cased.
+pub fn check_words(
+    mnemonic: &str,
+    language: bip39::Language,
+    max_suggestions: usize,
+    max_distance: usize,
+) -> Result<(), crate::error::CliError> {
+    match find_invalid_words(mnemonic, language, max_suggestions, max_distance)
+        .into_iter()
+        .next()
+    {
+        Some((position, word, suggestions)) => Err(crate::error::CliError::InvalidWord {
+            word,
+            position,
+            suggestions,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Find invalid words in a mnemonic, each with up to `max_suggestions`
+/// suggestions no more than `max_distance` edits away
+///
+/// Expects NFKD input, as produced by `normalize_mnemonic`. See
+/// `suggest_words` for how suggestions are chosen per script.
 #[must_use]
 pub fn find_invalid_words(
     mnemonic: &str,
     language: bip39::Language,
+    max_suggestions: usize,
+    max_distance: usize,
 ) -> Vec<(usize, String, Vec<String>)> {
     let words: Vec<&str> = mnemonic.split_whitespace().collect();
-    let word_list = language.word_list();
+    let word_set = word_set(language);
     let mut invalid_words = Vec::new();
 
     for (index, word) in words.iter().enumerate() {
         let word_lower = word.to_lowercase();
-        if !word_list.contains(&word_lower.as_str()) {
-            // Find similar words using simple edit distance
-            let suggestions: Vec<String> = word_list
-                .iter()
-                .filter(|&w| edit_distance(&word_lower, w) <= 2)
-                .take(3)
-                .map(std::string::ToString::to_string)
-                .collect();
-
+        if !word_set.contains(word_lower.as_str()) {
+            let suggestions = suggest_words(&word_lower, language, max_suggestions, max_distance);
             invalid_words.push((index + 1, (*word).to_string(), suggestions));
         }
     }
@@ -104,59 +452,637 @@ pub fn find_invalid_words(
     invalid_words
 }
 
+/// SHA-256 of each embedded word list, hashed as by `word_list_sha256`
+pub const WORD_LIST_SHA256: [(bip39::Language, &str); 10] = [
+    (
+        bip39::Language::English,
+        "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda",
+    ),
+    (
+        bip39::Language::SimplifiedChinese,
+        "5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726",
+    ),
+    (
+        bip39::Language::TraditionalChinese,
+        "417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f",
+    ),
+    (
+        bip39::Language::Czech,
+        "7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc",
+    ),
+    (
+        bip39::Language::French,
+        "ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59",
+    ),
+    (
+        bip39::Language::Italian,
+        "d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2",
+    ),
+    (
+        bip39::Language::Japanese,
+        "2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd",
+    ),
+    (
+        bip39::Language::Korean,
+        "9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60",
+    ),
+    (
+        bip39::Language::Portuguese,
+        "2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f",
+    ),
+    (
+        bip39::Language::Spanish,
+        "46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b",
+    ),
+];
+
+/// Hex SHA-256 of a word list, one word per line with `\n` endings
+///
+/// That is the layout of the BIP39 reference files, so the English digest
+/// equals `sha256sum english.txt`.
+#[must_use]
+pub fn word_list_sha256(language: bip39::Language) -> String {
+    let mut hasher = Sha256::new();
+    for word in language.word_list() {
+        hasher.update(word.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Languages in `expected` whose embedded word list hashes to something else
+#[must_use]
+pub fn word_list_mismatches(expected: &[(bip39::Language, &str)]) -> Vec<bip39::Language> {
+    expected
+        .iter()
+        .filter(|(language, digest)| word_list_sha256(*language) != *digest)
+        .map(|(language, _)| *language)
+        .collect()
+}
+
+/// Word list of `language` as a set, built once per process for O(1) membership checks
+fn word_set(language: bip39::Language) -> &'static HashSet<&'static str> {
+    static WORD_SETS: OnceLock<HashMap<bip39::Language, HashSet<&'static str>>> = OnceLock::new();
+
+    let sets = WORD_SETS.get_or_init(|| {
+        bip39::Language::ALL
+            .iter()
+            .map(|&language| (language, language.word_list().iter().copied().collect()))
+            .collect()
+    });
+    &sets[&language]
+}
+
+/// Up to `limit` word list entries the user may have meant by `word`, closest first
+///
+/// - Latin scripts: words within `max_distance` edits, sorted by distance
+///   and then alphabetically, each at most once.
+/// - Japanese and Korean: the same, but the NFKD forms compare readings
+///   rather than glyphs. Kana split off their voicing marks (が becomes か
+///   plus ゛) and Hangul syllables split into jamo, so a wrong voicing mark or
+///   vowel costs one edit.
+/// - Chinese: each word is a single character, so every word is one edit
+///   from every other and edit distance says nothing. The simplified and
+///   traditional lists are index-aligned, so a character from the other
+///   list maps to its counterpart; anything else gets no suggestions.
+fn suggest_words(
+    word: &str,
+    language: bip39::Language,
+    limit: usize,
+    max_distance: usize,
+) -> Vec<String> {
+    let counterpart = match language {
+        bip39::Language::SimplifiedChinese => Some(bip39::Language::TraditionalChinese),
+        bip39::Language::TraditionalChinese => Some(bip39::Language::SimplifiedChinese),
+        _ => None,
+    };
+    if let Some(other) = counterpart {
+        return other
+            .find_word(word)
+            .map(|index| language.word_list()[usize::from(index)].to_string())
+            .into_iter()
+            .take(limit)
+            .collect();
+    }
+
+    let word_len = word.chars().count();
+    let mut candidates: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .filter_map(|&candidate| {
+            // Edit distance is at least the length difference, so skip the
+            // quadratic comparison for words that cannot be close enough
+            if word_len.abs_diff(candidate.chars().count()) > max_distance {
+                return None;
+            }
+            let distance = edit_distance(word, candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+    // Sorting puts any repeat next to its twin, so dedup catches them all
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    candidates
+        .into_iter()
+        .take(limit)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// All words in the word list that start with the given prefix
+#[must_use]
+pub fn words_with_prefix(prefix: &str, language: bip39::Language) -> Vec<&'static str> {
+    let mut prefix: String = prefix.nfkd().collect();
+    if is_latin_script(language) {
+        prefix = prefix.to_lowercase();
+    }
+
+    language
+        .word_list()
+        .iter()
+        .filter(|word| word.starts_with(prefix.as_str()))
+        .copied()
+        .collect()
+}
+
 /// Simple edit distance calculation for word suggestions
 #[must_use]
 pub fn edit_distance(s1: &str, s2: &str) -> usize {
-    let s1_chars: Vec<char> = s1.chars().collect();
     let s2_chars: Vec<char> = s2.chars().collect();
-    let m = s1_chars.len();
-    let n = s2_chars.len();
 
-    let mut dp = vec![vec![0; n + 1]; m + 1];
+    // Levenshtein distance keeping only the previous and current DP rows
+    let mut previous: Vec<usize> = (0..=s2_chars.len()).collect();
+    let mut current = vec![0; s2_chars.len() + 1];
+
+    for (i, c1) in s1.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &c2) in s2_chars.iter().enumerate() {
+            current[j + 1] = if c1 == c2 {
+                previous[j]
+            } else {
+                1 + previous[j].min(previous[j + 1]).min(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[s2_chars.len()]
+}
+
+/// Fail fast instead of blocking on hidden input nobody can type
+fn require_terminal() -> Result<(), crate::error::CliError> {
+    use std::io::IsTerminal;
 
-    for (i, row) in dp.iter_mut().enumerate().take(m + 1) {
-        row[0] = i;
+    if std::io::stdin().is_terminal() {
+        return Ok(());
     }
-    for j in 0..=n {
-        dp[0][j] = j;
+    Err(crate::error::CliError::SecureInputError {
+        message: "Secure input requires an interactive terminal, but stdin is not a terminal"
+            .to_string(),
+        hint: "Run interactively, or pass the value with --input-file or --passphrase-env instead"
+            .to_string(),
+    })
+}
+
+/// Source of hidden interactive input
+///
+/// Prompts go through this trait rather than straight to the terminal, so the
+/// logic around them (validation, confirmation, strength gates) can be driven
+/// by canned input in tests.
+pub trait PassphraseReader {
+    /// Fail before any prompt or banner is shown if input cannot be read at all
+    fn ensure_available(&self) -> Result<(), crate::error::CliError> {
+        Ok(())
     }
 
-    for i in 1..=m {
-        for j in 1..=n {
-            if s1_chars[i - 1] == s2_chars[j - 1] {
-                dp[i][j] = dp[i - 1][j - 1];
-            } else {
-                dp[i][j] = 1 + dp[i - 1][j].min(dp[i][j - 1]).min(dp[i - 1][j - 1]);
+    /// Show `prompt` and read one line without echoing it
+    fn read_hidden(&mut self, prompt: &str) -> Result<String, crate::error::CliError>;
+}
+
+/// Reads from the terminal with echo disabled, via `rpassword`
+///
+/// With a `timeout`, each read happens on a helper thread and is abandoned
+/// once the timeout expires.
+pub struct TerminalReader {
+    timeout: Option<std::time::Duration>,
+}
+
+impl TerminalReader {
+    #[must_use]
+    pub const fn new(timeout: Option<std::time::Duration>) -> Self {
+        Self { timeout }
+    }
+}
+
+impl PassphraseReader for TerminalReader {
+    fn ensure_available(&self) -> Result<(), crate::error::CliError> {
+        require_terminal()
+    }
+
+    fn read_hidden(&mut self, prompt: &str) -> Result<String, crate::error::CliError> {
+        let term = Term::stderr();
+        let prompt_style = Style::new().bold().cyan();
+
+        term.write_line(&format!("{}", prompt_style.apply_to(prompt)))?;
+        term.write_str("🔒 ")?;
+
+        let result = match self.timeout {
+            None => rpassword::read_password(),
+            Some(timeout) => read_password_with_timeout(timeout)?,
+        };
+
+        result.map_err(|e| crate::error::CliError::SecureInputError {
+            message: format!("Failed to read secure input: {e}"),
+            hint: "Ensure a terminal is attached and supports hidden input".to_string(),
+        })
+    }
+}
+
+/// Secure input for sensitive data (hidden from terminal history)
+pub fn secure_input(
+    reader: &mut impl PassphraseReader,
+    prompt: &str,
+) -> Result<String, crate::error::CliError> {
+    reader.ensure_available()?;
+    reader.read_hidden(prompt)
+}
+
+/// Mismatched confirmations allowed before `read_confirmed` gives up
+pub const MAX_CONFIRM_ATTEMPTS: usize = 3;
+
+/// Read a secret twice and accept it only when both entries match
+///
+/// Entries are compared in constant time, and every rejected entry is zeroized.
+pub fn read_confirmed(
+    reader: &mut impl PassphraseReader,
+    prompt: &str,
+) -> Result<String, crate::error::CliError> {
+    use subtle::ConstantTimeEq;
+
+    reader.ensure_available()?;
+    for attempt in 1..=MAX_CONFIRM_ATTEMPTS {
+        let mut first = reader.read_hidden(prompt)?;
+        let mut second = match reader.read_hidden("Enter it again to confirm:") {
+            Ok(second) => second,
+            Err(e) => {
+                first.zeroize();
+                return Err(e);
             }
+        };
+        let matched = bool::from(first.as_bytes().ct_eq(second.as_bytes()));
+        second.zeroize();
+        if matched {
+            return Ok(first);
+        }
+        first.zeroize();
+        if attempt < MAX_CONFIRM_ATTEMPTS {
+            eprintln!("✗ The entries do not match; please try again");
         }
     }
 
-    dp[m][n]
+    Err(crate::error::CliError::SecureInputError {
+        message: format!("The entries did not match in {MAX_CONFIRM_ATTEMPTS} attempts"),
+        hint: "A mistyped passphrase derives a different, unrecoverable wallet; type it carefully"
+            .to_string(),
+    })
 }
 
-/// Secure input for sensitive data (hidden from terminal history)
-pub fn secure_input(prompt: &str) -> Result<String, std::io::Error> {
-    let term = Term::stderr();
-    let prompt_style = Style::new().bold().cyan();
+/// Read a hidden line on a helper thread, giving up after `timeout`
+fn read_password_with_timeout(
+    timeout: std::time::Duration,
+) -> Result<std::io::Result<String>, crate::error::CliError> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        // If the receiver already gave up, wipe whatever was typed
+        if let Err(std::sync::mpsc::SendError(Ok(mut late))) =
+            sender.send(rpassword::read_password())
+        {
+            late.zeroize();
+        }
+    });
+
+    receiver.recv_timeout(timeout).map_err(|_| {
+        // The abandoned read left echo disabled; turn it back on
+        #[cfg(unix)]
+        if let Ok(tty) = std::fs::File::open("/dev/tty") {
+            let _ = std::process::Command::new("stty")
+                .arg("echo")
+                .stdin(tty)
+                .status();
+        }
+        let _ = Term::stderr().write_line("");
+
+        crate::error::CliError::SecureInputError {
+            message: format!("No input received within {} seconds", timeout.as_secs()),
+            hint: "Increase --input-timeout, or omit it to wait indefinitely".to_string(),
+        }
+    })
+}
+
+/// Read a passphrase from an environment variable (keeps it out of the process listing)
+pub fn read_passphrase_env(name: &str) -> Result<String, crate::error::CliError> {
+    std::env::var(name).map_err(|_| crate::error::CliError::EnvVarNotSet {
+        name: name.to_string(),
+    })
+}
+
+/// Largest input file accepted; anything bigger is almost certainly not a mnemonic or hex entropy
+pub const MAX_INPUT_FILE_BYTES: u64 = 4096;
+
+/// Read a mnemonic or hex entropy from a file, trimming trailing whitespace
+pub fn read_input_file(path: &std::path::Path) -> Result<String, crate::error::CliError> {
+    let file_error = |message: String, hint: &str| crate::error::CliError::InputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| file_error(e.to_string(), "Check that the file exists and is readable"))?;
+    if metadata.len() > MAX_INPUT_FILE_BYTES {
+        return Err(file_error(
+            format!(
+                "file is {} bytes (limit {MAX_INPUT_FILE_BYTES})",
+                metadata.len()
+            ),
+            "The file should contain only a mnemonic or hex entropy",
+        ));
+    }
+
+    let mut contents = std::fs::read_to_string(path)
+        .map_err(|e| file_error(e.to_string(), "The file must contain UTF-8 text"))?;
+    let trimmed = contents.trim_end().to_string();
+    contents.zeroize(); // Clear the file buffer from memory
+
+    Ok(trimmed)
+}
+
+/// Largest file accepted by `read_word_list_file`
+pub const MAX_WORD_LIST_FILE_BYTES: u64 = 64 * 1024;
+
+/// Read a custom word list of exactly 2048 unique words, one per line
+///
+/// Words are normalized like Latin-script mnemonic input (NFKD, lowercase) so
+/// that lookups match normalized mnemonics. Blank lines are ignored.
+pub fn read_word_list_file(path: &std::path::Path) -> Result<Vec<String>, crate::error::CliError> {
+    let file_error = |message: String, hint: &str| crate::error::CliError::InputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+    let list_hint = "A word list needs exactly 2048 unique words, one per line";
+
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| file_error(e.to_string(), "Check that the file exists and is readable"))?;
+    if metadata.len() > MAX_WORD_LIST_FILE_BYTES {
+        return Err(file_error(
+            format!(
+                "file is {} bytes (limit {MAX_WORD_LIST_FILE_BYTES})",
+                metadata.len()
+            ),
+            list_hint,
+        ));
+    }
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| file_error(e.to_string(), "The file must contain UTF-8 text"))?;
+
+    let words: Vec<String> = contents
+        .lines()
+        .map(|line| normalize_mnemonic(line, bip39::Language::English))
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.len() != 2048 {
+        return Err(file_error(
+            format!("expected 2048 words, found {}", words.len()),
+            list_hint,
+        ));
+    }
+    if let Some(line) = words.iter().find(|word| word.contains(' ')) {
+        return Err(file_error(
+            format!("'{line}' is more than one word"),
+            list_hint,
+        ));
+    }
+    let mut seen = HashSet::new();
+    if let Some(word) = words.iter().find(|word| !seen.insert(word.as_str())) {
+        return Err(file_error(
+            format!("'{word}' appears more than once"),
+            list_hint,
+        ));
+    }
+
+    Ok(words)
+}
+
+/// Spell a mnemonic's word indices with a custom word list
+#[must_use]
+pub fn to_custom_words(mnemonic: &bip39::Mnemonic, word_list: &[String]) -> String {
+    mnemonic
+        .word_indices()
+        .map(|index| word_list[index].as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a normalized mnemonic spelled with a custom word list
+///
+/// The words are mapped to their indices and checked like any BIP39 mnemonic,
+/// so the checksum stays standard. The result is equivalent to the English
+/// mnemonic with the same indices.
+pub fn parse_custom_mnemonic(
+    mnemonic: &str,
+    word_list: &[String],
+) -> Result<bip39::Mnemonic, bip39::Error> {
+    let english = bip39::Language::English.word_list();
+    let mut indexed = Vec::new();
+    for (i, word) in mnemonic.split_whitespace().enumerate() {
+        let index = word_list
+            .iter()
+            .position(|candidate| candidate == word)
+            .ok_or(bip39::Error::UnknownWord(i))?;
+        indexed.push(english[index]);
+    }
+
+    let mut phrase = indexed.join(" ");
+    let parsed = bip39::Mnemonic::parse_in_normalized(bip39::Language::English, &phrase);
+    phrase.zeroize();
+    parsed
+}
+
+/// Read a passphrase from a file, dropping a single trailing newline
+///
+/// Unlike `read_input_file`, other whitespace is kept: it is part of the passphrase.
+pub fn read_passphrase_file(path: &std::path::Path) -> Result<String, crate::error::CliError> {
+    let file_error = |message: String, hint: &str| crate::error::CliError::InputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| file_error(e.to_string(), "Check that the file exists and is readable"))?;
+    if metadata.len() > MAX_INPUT_FILE_BYTES {
+        return Err(file_error(
+            format!(
+                "file is {} bytes (limit {MAX_INPUT_FILE_BYTES})",
+                metadata.len()
+            ),
+            "The file should contain only the passphrase",
+        ));
+    }
+
+    let mut contents = std::fs::read_to_string(path)
+        .map_err(|e| file_error(e.to_string(), "The file must contain UTF-8 text"))?;
+    let end = contents
+        .strip_suffix("\r\n")
+        .or_else(|| contents.strip_suffix('\n'))
+        .map_or(contents.len(), str::len);
+    let passphrase = contents[..end].to_string();
+    contents.zeroize(); // Clear the file buffer from memory
+
+    Ok(passphrase)
+}
+
+/// Write a secret plus newline to an inherited file descriptor, then close it
+///
+/// Lets a parent process (e.g. an HSM bridge) receive the secret over a pipe
+/// without it reaching a terminal or a file. Standard streams are rejected.
+pub fn write_secret_fd(fd: i32, contents: &str) -> Result<(), crate::error::CliError> {
+    let fd_error = |message: String, hint: &str| crate::error::CliError::OutputFileError {
+        path: format!("fd {fd}"),
+        message,
+        hint: hint.to_string(),
+    };
+
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::io::FromRawFd;
+
+        if fd < 3 {
+            return Err(fd_error(
+                "standard streams cannot be used".to_string(),
+                "Pass a descriptor opened by the parent process, e.g. the write end of a pipe",
+            ));
+        }
+        let is_open = std::fs::metadata(format!("/dev/fd/{fd}")).is_ok();
+        if !is_open {
+            return Err(fd_error(
+                "descriptor is not open".to_string(),
+                "The parent process must open the descriptor and let it be inherited",
+            ));
+        }
+
+        // SAFETY: the descriptor is open and was handed to us to own; nothing
+        // else in this process uses it, and dropping the File closes it
+        let mut file = unsafe { std::fs::File::from_raw_fd(fd) };
+        let mut buffer = format!("{contents}\n").into_bytes();
+        let result = file.write_all(&buffer).and_then(|()| file.flush());
+        buffer.zeroize();
+        result.map_err(|e| fd_error(e.to_string(), "Check that the descriptor is writable"))
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = contents;
+        Err(fd_error(
+            "--out-fd is only supported on Unix".to_string(),
+            "Use --output-file instead",
+        ))
+    }
+}
+
+/// Ask a yes/no question on the terminal, defaulting to no
+///
+/// `assume_yes` answers yes without asking. Without a terminal to ask on the
+/// answer is no, so scripted runs must opt in with `--assume-yes`.
+pub fn confirm(prompt: &str, assume_yes: bool) -> Result<bool, crate::error::CliError> {
+    use std::io::{BufRead, IsTerminal};
+
+    if assume_yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Ok(false);
+    }
 
-    term.write_line(&format!("{}", prompt_style.apply_to(prompt)))?;
-    term.write_str("🔒 ")?;
+    Term::stderr().write_str(&format!("{prompt} [y/N] "))?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Write a secret to a file that only the owner can read.
+/// On Unix the file is created with mode 0600 before any bytes are written.
+pub fn write_secret_file(
+    path: &std::path::Path,
+    contents: &str,
+    force: bool,
+) -> Result<(), crate::error::CliError> {
+    use std::io::Write;
+
+    let file_error = |message: String, hint: &str| crate::error::CliError::OutputFileError {
+        path: path.display().to_string(),
+        message,
+        hint: hint.to_string(),
+    };
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true);
+    if force {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
 
-    match rpassword::read_password() {
-        Ok(mut input) => {
-            let result = input.clone();
-            input.zeroize(); // Clear the input from memory
-            Ok(result)
+    let mut file = options.open(path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::AlreadyExists {
+            file_error(
+                "file already exists".to_string(),
+                "Use --force or --assume-yes to overwrite it",
+            )
+        } else {
+            file_error(e.to_string(), "Check the directory exists and is writable")
         }
-        Err(e) => Err(e),
+    })?;
+
+    // An existing file keeps its old mode when opened, so tighten it explicitly
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .map_err(|e| file_error(e.to_string(), "Check file ownership"))?;
+    }
+    #[cfg(not(unix))]
+    {
+        let term = Term::stderr();
+        term.write_line(
+            "Note: file permissions cannot be restricted on this platform; \
+             protect the output file manually",
+        )?;
     }
+
+    let mut line = format!("{contents}\n");
+    let result = file.write_all(line.as_bytes());
+    line.zeroize();
+    result.map_err(|e| file_error(e.to_string(), "Check available disk space"))
 }
 
 /// Secure input for mnemonics with validation
 pub fn secure_mnemonic_input(
+    reader: &mut impl PassphraseReader,
     prompt: &str,
     language: bip39::Language,
+    max_suggestions: usize,
+    max_distance: usize,
 ) -> Result<String, crate::error::CliError> {
+    reader.ensure_available()?;
+
     let warning_style = Style::new().bold().yellow();
     let term = Term::stderr();
 
@@ -169,26 +1095,16 @@ pub fn secure_mnemonic_input(
     term.write_line("• Consider using an air-gapped system for maximum security")?;
     term.write_line("")?;
 
-    let mut mnemonic =
-        secure_input(prompt).map_err(|e| crate::error::CliError::InvalidHexString {
-            message: format!("Failed to read secure input: {e}"),
-            position: None,
-            hint: "Ensure terminal supports secure input".to_string(),
-        })?;
+    let mut raw_input = reader.read_hidden(prompt)?;
+    let mut mnemonic = normalize_mnemonic(&raw_input, language);
+    raw_input.zeroize();
 
     // Validate the mnemonic
     validate_mnemonic_word_count(&mnemonic)?;
 
-    // Check for invalid words
-    let invalid_words = find_invalid_words(&mnemonic, language);
-    if !invalid_words.is_empty() {
+    if let Err(e) = check_words(&mnemonic, language, max_suggestions, max_distance) {
         mnemonic.zeroize();
-        let (position, word, suggestions) = &invalid_words[0];
-        return Err(crate::error::CliError::InvalidWord {
-            word: word.clone(),
-            position: *position,
-            suggestions: suggestions.clone(),
-        });
+        return Err(e);
     }
 
     Ok(mnemonic)
@@ -197,7 +1113,8 @@ pub fn secure_mnemonic_input(
 /// Entropy quality assessment
 #[derive(Debug, Clone)]
 pub struct EntropyQuality {
-    pub score: f64, // 0.0 to 1.0
+    pub score: f64,           // 0.0 to 1.0
+    pub shannon_entropy: f64, // bits per byte
     pub issues: Vec<String>,
     pub recommendations: Vec<String>,
 }
@@ -239,7 +1156,9 @@ pub fn analyze_entropy_quality(entropy: &[u8]) -> EntropyQuality {
 
     // 5. Entropy estimation using Shannon entropy
     let shannon_entropy = calculate_shannon_entropy(entropy);
-    let max_entropy = 8.0; // Maximum entropy for bytes
+    // A buffer of n bytes holds at most n distinct values, so its Shannon
+    // entropy cannot exceed log2(n) bits per byte (8 bits once n >= 256)
+    let max_entropy = (entropy.len().clamp(2, 256) as f64).log2();
     let entropy_ratio = shannon_entropy / max_entropy;
 
     if entropy_ratio < 0.8 {
@@ -265,54 +1184,154 @@ pub fn analyze_entropy_quality(entropy: &[u8]) -> EntropyQuality {
 
     EntropyQuality {
         score,
+        shannon_entropy,
         issues,
         recommendations,
     }
 }
 
-/// Check for simple repeating patterns
-fn has_repeating_pattern(data: &[u8]) -> bool {
-    if data.len() < 4 {
-        return false;
-    }
+/// Score distribution of the entropy analyzer over many OsRng samples
+#[derive(Debug, Clone)]
+pub struct SelfTestReport {
+    pub samples: usize,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub flagged: usize, // samples scoring below 0.8
+}
 
-    // Check for 2-byte patterns
-    for i in 0..data.len() - 3 {
-        if data[i] == data[i + 2] && data[i + 1] == data[i + 3] {
-            return true;
+/// Draw `samples` buffers of `size` bytes from `OsRng` and score each one
+///
+/// Genuine RNG output should score near 1.0 on average; a low mean points at
+/// a broken RNG or at heuristics that misfire on random data.
+#[must_use]
+pub fn entropy_self_test(samples: usize, size: usize) -> SelfTestReport {
+    use rand::{rngs::OsRng, RngCore};
+
+    let mut buffer = vec![0u8; size];
+    let mut total = 0.0;
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    let mut flagged = 0;
+
+    for _ in 0..samples {
+        OsRng.fill_bytes(&mut buffer);
+        let score = analyze_entropy_quality(&buffer).score;
+        total += score;
+        min = min.min(score);
+        max = max.max(score);
+        if score < 0.8 {
+            flagged += 1;
         }
     }
+    buffer.zeroize();
+
+    SelfTestReport {
+        samples,
+        mean: total / samples as f64,
+        min,
+        max,
+        flagged,
+    }
+}
 
-    // Check for 4-byte patterns
-    for i in 0..data.len() - 7 {
-        if data[i..i + 4] == data[i + 4..i + 8] {
-            return true;
-        }
+/// Check for repeating patterns occurring more often than chance allows
+///
+/// Any single ABAB or ABCDABCD repeat is expected now and then in random
+/// data, so only flag when the number of repeats is well above the rate a
+/// uniform source would produce for a buffer of this length.
+fn has_repeating_pattern(data: &[u8]) -> bool {
+    // 2-byte patterns: data[i..i + 2] == data[i + 2..i + 4]
+    let pairs = data.windows(4).filter(|w| w[..2] == w[2..]).count();
+    if exceeds_random_rate(pairs, data.windows(4).len(), 2) {
+        return true;
     }
 
-    false
+    // 4-byte patterns: data[i..i + 4] == data[i + 4..i + 8]
+    let quads = data.windows(8).filter(|w| w[..4] == w[4..]).count();
+    exceeds_random_rate(quads, data.windows(8).len(), 4)
 }
 
-/// Byte frequency test - checks if bytes are roughly uniformly distributed
-fn byte_frequency_test(data: &[u8]) -> f64 {
-    let mut counts = [0u32; 256];
+/// Whether `observed` repeats of a `width`-byte block across `positions`
+/// offsets is significantly more than uniform random bytes would give
+///
+/// Each offset repeats with probability 256^-width, so the count is roughly
+/// Poisson with mean `positions / 256^width`. Flag counts more than four
+/// standard deviations above that mean, and never flag a lone repeat.
+fn exceeds_random_rate(observed: usize, positions: usize, width: i32) -> bool {
+    let expected = positions as f64 / 256f64.powi(width);
+    let threshold = (expected + 4.0 * expected.sqrt()).max(1.0);
+    observed as f64 > threshold
+}
 
+/// Minimum input length for the byte-level chi-squared test
+///
+/// Chi-squared needs an expected count of at least 5 per bucket, i.e.
+/// 5 * 256 bytes. BIP39 entropy (16-32 bytes) is far below this.
+const CHI_SQUARED_MIN_LEN: usize = 5 * 256;
+
+/// Number of occurrences of each byte value
+#[must_use]
+pub fn byte_counts(data: &[u8]) -> [u32; 256] {
+    let mut counts = [0u32; 256];
     for &byte in data {
-        counts[byte as usize] += 1;
+        counts[usize::from(byte)] += 1;
     }
+    counts
+}
 
-    let expected = data.len() as f64 / 256.0;
-    let mut chi_squared = 0.0;
+/// Number of occurrences of each nibble value, counting both halves of every byte
+#[must_use]
+pub fn nibble_counts(data: &[u8]) -> [u32; 16] {
+    let mut counts = [0u32; 16];
+    for (byte, count) in byte_counts(data).iter().enumerate() {
+        counts[byte >> 4] += count;
+        counts[byte & 0xf] += count;
+    }
+    counts
+}
 
-    for count in &counts {
-        let diff = f64::from(*count) - expected;
-        chi_squared += (diff * diff) / expected;
+/// Frequency test - checks that the data is not biased, returning a 0-1 score
+///
+/// Inputs of at least `CHI_SQUARED_MIN_LEN` bytes get a chi-squared test over
+/// the 256 byte values (255 degrees of freedom). Shorter inputs have too few
+/// samples per bucket for that to mean anything, so they get a monobit test
+/// instead: the count of set bits should be close to half of all bits.
+///
+/// Either statistic is converted to a z-score, which maps to the score
+/// linearly: z <= 2 scores 1.0, each further standard deviation costs 0.25,
+/// and z >= 6 scores 0.0. Random data scores below 0.7 (z > 3.2) well under
+/// 1% of the time.
+fn byte_frequency_test(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
     }
 
-    // Normalize chi-squared to a 0-1 score
-    // This is a simplified heuristic
-    let max_chi_squared = data.len() as f64 * 4.0;
-    1.0 - (chi_squared / max_chi_squared).min(1.0)
+    let z = if data.len() >= CHI_SQUARED_MIN_LEN {
+        let counts = byte_counts(data);
+
+        let expected = data.len() as f64 / 256.0;
+        let chi_squared: f64 = counts
+            .iter()
+            .map(|&count| {
+                let diff = f64::from(count) - expected;
+                diff * diff / expected
+            })
+            .sum();
+
+        // Chi-squared with k degrees of freedom has mean k and variance 2k.
+        // Only an excess is suspicious; a low value means a very even spread.
+        let dof = 255.0;
+        ((chi_squared - dof) / (2.0 * dof).sqrt()).max(0.0)
+    } else {
+        let bits = data.len() as f64 * 8.0;
+        let ones: u32 = data.iter().map(|byte| byte.count_ones()).sum();
+
+        // Set bits are binomial(n, 1/2): mean n/2, standard deviation sqrt(n)/2
+        (f64::from(ones) - bits / 2.0).abs() / (bits.sqrt() / 2.0)
+    };
+
+    (1.0 - (z - 2.0).max(0.0) / 4.0).clamp(0.0, 1.0)
 }
 
 /// Check for sequential patterns like 01234567 or FEDCBA98
@@ -347,12 +1366,7 @@ fn has_sequential_pattern(data: &[u8]) -> bool {
 
 /// Calculate Shannon entropy
 fn calculate_shannon_entropy(data: &[u8]) -> f64 {
-    let mut counts = [0u32; 256];
-
-    for &byte in data {
-        counts[byte as usize] += 1;
-    }
-
+    let counts = byte_counts(data);
     let total = data.len() as f64;
     let mut entropy = 0.0;
 
@@ -418,40 +1432,73 @@ pub fn show_security_warnings() -> Result<(), std::io::Error> {
 /// Check if we're likely running in an air-gapped environment
 #[must_use]
 pub fn check_air_gapped_environment() -> AirGapStatus {
+    assess_air_gap::<fn() -> bool>(None)
+}
+
+/// Like [`check_air_gapped_environment`], but only penalize interfaces when `probe`
+/// reports real connectivity
+///
+/// The probe is skipped when no network interfaces exist.
+#[must_use]
+pub fn check_air_gapped_environment_deep<F: FnOnce() -> bool>(probe: F) -> AirGapStatus {
+    assess_air_gap(Some(probe))
+}
+
+fn assess_air_gap<F: FnOnce() -> bool>(probe: Option<F>) -> AirGapStatus {
     let mut warnings = Vec::new();
     let mut score = 1.0;
+    let mut connectivity = Connectivity::NotProbed;
 
-    // Check network interfaces (simplified check)
-    if std::path::Path::new("/sys/class/net").exists() {
-        if let Ok(entries) = std::fs::read_dir("/sys/class/net") {
-            let mut active_interfaces = 0;
-            for entry in entries.flatten() {
-                let name = entry.file_name();
-                let name_str = name.to_string_lossy();
-                if name_str != "lo" {
-                    // Skip loopback
-                    active_interfaces += 1;
-                }
-            }
-
-            if active_interfaces > 0 {
-                warnings.push(
-                    "Network interfaces detected - consider disconnecting for air-gapped operation"
-                        .to_string(),
-                );
-                score *= 0.7;
+    // Check network interfaces
+    let active_interfaces = if cfg!(target_os = "linux") {
+        count_sysfs_interfaces()
+    } else {
+        count_interfaces()
+    };
+
+    match (active_interfaces, probe) {
+        (Some(0), Some(_)) => connectivity = Connectivity::NoInterfaces,
+        (_, Some(probe)) => {
+            if probe() {
+                connectivity = Connectivity::Online;
+                warnings
+                    .push("Network connectivity confirmed - this machine is online".to_string());
+                score *= 0.4;
+            } else {
+                connectivity = Connectivity::InterfacesOffline;
             }
         }
+        (Some(0), None) => {}
+        (Some(_), None) => {
+            warnings.push(
+                "Network interfaces detected - consider disconnecting for air-gapped operation"
+                    .to_string(),
+            );
+            score *= 0.7;
+        }
+        (None, None) => {
+            warnings.push("Could not enumerate network interfaces".to_string());
+            score *= 0.9;
+        }
     }
 
-    // Check for common signs of network activity
-    if std::path::Path::new("/tmp/.X11-unix").exists() {
-        warnings.push("X11 server detected - be cautious of screen capture malware".to_string());
-        score *= 0.9;
+    if connectivity == Connectivity::NotProbed && has_default_route() {
+        warnings.push(
+            "Default network route configured - this machine can likely reach other networks"
+                .to_string(),
+        );
+        score *= 0.7;
     }
 
-    // Check if we're running in a VM (basic check)
-    if std::path::Path::new("/sys/class/dmi/id/product_name").exists() {
+    if cfg!(target_os = "linux") {
+        // Check for common signs of network activity
+        if std::path::Path::new("/tmp/.X11-unix").exists() {
+            warnings
+                .push("X11 server detected - be cautious of screen capture malware".to_string());
+            score *= 0.9;
+        }
+
+        // Check if we're running in a VM (basic check)
         if let Ok(product) = std::fs::read_to_string("/sys/class/dmi/id/product_name") {
             if product.to_lowercase().contains("virtual")
                 || product.to_lowercase().contains("vmware")
@@ -467,14 +1514,114 @@ pub fn check_air_gapped_environment() -> AirGapStatus {
         score,
         is_air_gapped: score > 0.8 && warnings.is_empty(),
         warnings,
+        connectivity,
     }
 }
 
+/// Try a short TCP connection to the configured DNS servers and a public resolver
+#[must_use]
+pub fn probe_connectivity() -> bool {
+    let timeout = std::time::Duration::from_millis(800);
+    let mut targets: Vec<std::net::SocketAddr> = std::fs::read_to_string("/etc/resolv.conf")
+        .map(|conf| {
+            conf.lines()
+                .filter_map(|line| line.strip_prefix("nameserver"))
+                .filter_map(|addr| addr.trim().parse::<std::net::IpAddr>().ok())
+                .filter(|ip| !ip.is_loopback())
+                .map(|ip| std::net::SocketAddr::new(ip, 53))
+                .collect()
+        })
+        .unwrap_or_default();
+    targets.push(std::net::SocketAddr::from(([1, 1, 1, 1], 53)));
+
+    targets
+        .iter()
+        .any(|addr| std::net::TcpStream::connect_timeout(addr, timeout).is_ok())
+}
+
+/// Count non-loopback interfaces listed in `/sys/class/net` (Linux)
+fn count_sysfs_interfaces() -> Option<usize> {
+    let entries = std::fs::read_dir("/sys/class/net").ok()?;
+    Some(
+        entries
+            .flatten()
+            .filter(|entry| entry.file_name() != "lo")
+            .count(),
+    )
+}
+
+/// Count non-loopback interfaces via `getifaddrs` (macOS) or the adapter list (Windows)
+fn count_interfaces() -> Option<usize> {
+    let interfaces = if_addrs::get_if_addrs().ok()?;
+    let mut names: Vec<String> = interfaces
+        .into_iter()
+        .filter(|iface| !iface.is_loopback())
+        .map(|iface| iface.name)
+        .collect();
+    names.sort();
+    names.dedup();
+    Some(names.len())
+}
+
+/// Whether a default route (0.0.0.0/0 or ::/0) is configured
+fn has_default_route() -> bool {
+    if cfg!(target_os = "linux") {
+        let ipv4 = std::fs::read_to_string("/proc/net/route").is_ok_and(|table| {
+            table
+                .lines()
+                .skip(1)
+                .any(|line| line.split_whitespace().nth(1) == Some("00000000"))
+        });
+        let ipv6 = std::fs::read_to_string("/proc/net/ipv6_route").is_ok_and(|table| {
+            table.lines().any(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                fields.len() > 9
+                    && fields[0] == "00000000000000000000000000000000"
+                    && fields[1] == "00"
+                    && fields[9] != "lo"
+            })
+        });
+        ipv4 || ipv6
+    } else if cfg!(target_os = "macos") {
+        command_output("route", &["-n", "get", "default"])
+            .is_some_and(|out| out.contains("gateway:"))
+    } else if cfg!(target_os = "windows") {
+        command_output("route", &["print", "-4", "0.0.0.0"]).is_some_and(|out| {
+            out.lines()
+                .any(|line| line.trim_start().starts_with("0.0.0.0"))
+        })
+    } else {
+        false
+    }
+}
+
+/// Run a command and return its stdout if it succeeded
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
 #[derive(Debug, Clone)]
 pub struct AirGapStatus {
     pub score: f64,
     pub warnings: Vec<String>,
     pub is_air_gapped: bool,
+    pub connectivity: Connectivity,
+}
+
+/// Result of the opt-in connectivity probe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    NotProbed,
+    NoInterfaces,
+    InterfacesOffline,
+    Online,
 }
 
 /// Assess passphrase strength
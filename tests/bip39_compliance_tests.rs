@@ -1,83 +1,6 @@
 /// BIP39 compliance tests using official test vectors
 /// Reads test vectors from the official Trezor vectors.json file
-use serde_json::Value;
-
-#[derive(Debug, Clone)]
-struct TestVector {
-    entropy: String,
-    mnemonic: String,
-    seed: String,
-}
-
-fn load_test_vectors() -> Result<Vec<TestVector>, Box<dyn std::error::Error>> {
-    let vectors_json = include_str!("../test-vectors.json");
-    let data: Value = serde_json::from_str(vectors_json)?;
-
-    let english_vectors = data["english"]
-        .as_array()
-        .ok_or("Missing english vectors")?;
-
-    let mut vectors = Vec::new();
-    for vector in english_vectors {
-        let array = vector.as_array().ok_or("Invalid vector format")?;
-        if array.len() >= 4 {
-            vectors.push(TestVector {
-                entropy: array[0].as_str().unwrap_or("").to_string(),
-                mnemonic: array[1].as_str().unwrap_or("").to_string(),
-                seed: array[2].as_str().unwrap_or("").to_string(),
-            });
-        }
-    }
-
-    Ok(vectors)
-}
-
-/// Validate a test vector against our implementation
-/// Note: BIP39 vectors use "TREZOR" as passphrase for seed derivation
-fn validate_test_vector(vector: &TestVector) -> Result<(), String> {
-    use bip39::Mnemonic;
-
-    // 1. Test entropy -> mnemonic conversion
-    let entropy_bytes =
-        hex::decode(&vector.entropy).map_err(|e| format!("Failed to decode entropy: {e}"))?;
-
-    let generated_mnemonic = Mnemonic::from_entropy(&entropy_bytes)
-        .map_err(|e| format!("Failed to generate mnemonic from entropy: {e}"))?;
-
-    if generated_mnemonic.to_string() != vector.mnemonic {
-        return Err(format!(
-            "Mnemonic mismatch!\nExpected: {}\nGenerated: {}",
-            vector.mnemonic, generated_mnemonic
-        ));
-    }
-
-    // 2. Test mnemonic -> seed conversion with "TREZOR" passphrase
-    let parsed_mnemonic =
-        Mnemonic::parse(&vector.mnemonic).map_err(|e| format!("Failed to parse mnemonic: {e}"))?;
-
-    let generated_seed = parsed_mnemonic.to_seed("TREZOR");
-    let generated_seed_hex = hex::encode(generated_seed);
-
-    if generated_seed_hex != vector.seed {
-        return Err(format!(
-            "Seed mismatch!\nExpected: {}\nGenerated: {}",
-            vector.seed, generated_seed_hex
-        ));
-    }
-
-    // 3. Test mnemonic -> entropy conversion (round trip)
-    let extracted_entropy = parsed_mnemonic.to_entropy();
-    let extracted_entropy_hex = hex::encode(extracted_entropy);
-
-    if extracted_entropy_hex != vector.entropy {
-        return Err(format!(
-            "Entropy round-trip failed!\nOriginal: {}\nExtracted: {}",
-            vector.entropy, extracted_entropy_hex
-        ));
-    }
-
-    Ok(())
-}
+use bip39_cli::compliance::{load_test_vectors, validate_test_vector};
 
 #[cfg(test)]
 mod tests {
@@ -125,6 +48,19 @@ mod tests {
         validate_test_vector(&vectors[3]).expect("Vector 4 should pass");
     }
 
+    #[test]
+    fn test_vector_mismatch_is_reported_on_one_line() {
+        let mut vectors = load_test_vectors().expect("Should load test vectors");
+        vectors[0].seed = "00".repeat(64);
+
+        let message = validate_test_vector(&vectors[0]).expect_err("Wrong seed should fail");
+        assert!(
+            message.starts_with("Seed mismatch: expected 0000"),
+            "{message}"
+        );
+        assert!(!message.contains('\n'), "{message}");
+    }
+
     #[test]
     fn test_all_24_bip39_vectors() {
         let vectors = load_test_vectors().expect("Should load test vectors");
@@ -137,6 +73,41 @@ mod tests {
         println!("✅ All {} BIP39 test vectors passed!", vectors.len());
     }
 
+    #[test]
+    fn test_checksum_only_matches_full_parse() {
+        use bip39::{Language, Mnemonic};
+        use bip39_cli::security::verify_checksum;
+
+        let vectors = load_test_vectors().expect("Should load test vectors");
+
+        for vector in &vectors {
+            // Swapping the first word breaks the checksum for most vectors
+            let mut words: Vec<&str> = vector.mnemonic.split_whitespace().collect();
+            words[0] = if words[0] == "abandon" {
+                "ability"
+            } else {
+                "abandon"
+            };
+            let altered = words.join(" ");
+
+            for mnemonic in [vector.mnemonic.as_str(), altered.as_str()] {
+                let full = Mnemonic::parse_in_normalized(Language::English, mnemonic);
+                let fast = verify_checksum(mnemonic, Language::English);
+                assert_eq!(full.err(), fast.err(), "Verdicts differ for '{mnemonic}'");
+            }
+        }
+
+        assert_eq!(
+            verify_checksum("abandon abandon zzz", Language::English),
+            Err(bip39::Error::BadWordCount(3))
+        );
+        let unknown = "abandon ".repeat(11) + "zzz";
+        assert_eq!(
+            verify_checksum(&unknown, Language::English).err(),
+            Mnemonic::parse_in_normalized(Language::English, &unknown).err()
+        );
+    }
+
     #[test]
     fn test_entropy_to_mnemonic_deterministic() {
         use bip39::Mnemonic;
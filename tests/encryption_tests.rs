@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use bip39_cli::encryption::{decrypt_seed, encrypt_seed, ENCRYPTED_SEED_PREFIX};
+use bip39_cli::error::CliError;
+
+const KEY: [u8; 32] = [7; 32];
+
+fn seed() -> Vec<u8> {
+    (0..64).collect()
+}
+
+#[test]
+fn test_encrypt_decrypt_round_trip() {
+    let encrypted = encrypt_seed(&seed(), &KEY);
+    assert!(encrypted.starts_with(ENCRYPTED_SEED_PREFIX));
+    assert!(!encrypted.contains('\n'));
+
+    let decrypted = decrypt_seed(&encrypted, &KEY, Path::new("seed.enc")).unwrap();
+    assert_eq!(*decrypted, seed());
+
+    // A fresh salt and nonce every time, so equal seeds do not look equal at rest
+    assert_ne!(encrypt_seed(&seed(), &KEY), encrypted);
+}
+
+#[test]
+fn test_decrypt_rejects_wrong_key_and_tampering() {
+    let encrypted = encrypt_seed(&seed(), &KEY);
+    let path = Path::new("seed.enc");
+
+    assert!(matches!(
+        decrypt_seed(&encrypted, &[8; 32], path),
+        Err(CliError::DecryptionFailed { .. })
+    ));
+
+    // Flip one base64 character in the ciphertext
+    let mut tampered = encrypted.into_bytes();
+    let last = tampered.len() - 5;
+    tampered[last] = if tampered[last] == b'A' { b'B' } else { b'A' };
+    let tampered = String::from_utf8(tampered).unwrap();
+    assert!(matches!(
+        decrypt_seed(&tampered, &KEY, path),
+        Err(CliError::DecryptionFailed { .. })
+    ));
+
+    assert!(matches!(
+        decrypt_seed("not encrypted", &KEY, path),
+        Err(CliError::InputFileError { .. })
+    ));
+}
@@ -0,0 +1,406 @@
+use bip39_cli::error::CliError;
+
+#[test]
+fn test_weak_passphrase_error_display() {
+    let err = CliError::WeakPassphrase {
+        score: 0.25,
+        threshold: 0.6,
+    };
+    let message = err.to_string();
+
+    assert!(
+        message.starts_with("Passphrase strength too low: score 0.25 is below the required 0.60")
+    );
+    assert!(message.contains("--no-passphrase-check"));
+}
+
+#[test]
+fn test_secure_input_error_display() {
+    let err = CliError::SecureInputError {
+        message: "Failed to read secure input: not a terminal".to_string(),
+        hint: "Ensure a terminal is attached and supports hidden input".to_string(),
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "Failed to read secure input: not a terminal\n\
+         Hint: Ensure a terminal is attached and supports hidden input"
+    );
+}
+
+#[test]
+fn test_io_error_converts_to_secure_input_error() {
+    let io_error = std::io::Error::new(std::io::ErrorKind::BrokenPipe, "pipe closed");
+    let err = CliError::from(io_error);
+
+    assert!(matches!(err, CliError::SecureInputError { .. }));
+    assert!(err.to_string().starts_with("IO error: pipe closed"));
+}
+
+#[test]
+fn test_weak_entropy_error_display() {
+    let message = CliError::WeakEntropy {
+        score: 0.0,
+        threshold: 0.5,
+    }
+    .to_string();
+
+    assert!(message.contains("score 0.00 is below the required 0.50"));
+    assert!(message.contains("Hint:"));
+}
+
+#[test]
+fn test_exit_codes_per_category() {
+    let cases = [
+        (CliError::NoCommandProvided, 2),
+        (
+            CliError::UsageError {
+                message: String::new(),
+                hint: String::new(),
+            },
+            2,
+        ),
+        (
+            CliError::InvalidWordCount {
+                actual: 11,
+                expected: vec![12, 15, 18, 21, 24],
+                hint: String::new(),
+            },
+            3,
+        ),
+        (
+            CliError::InvalidWord {
+                word: "abandn".to_string(),
+                position: 1,
+                suggestions: vec![],
+            },
+            4,
+        ),
+        (
+            CliError::InvalidWordIndex {
+                value: "2048".to_string(),
+                position: None,
+            },
+            4,
+        ),
+        (
+            CliError::InvalidHexString {
+                message: String::new(),
+                position: None,
+                hint: String::new(),
+            },
+            5,
+        ),
+        (
+            CliError::InvalidEntropyLength {
+                actual: 30,
+                expected: vec![32],
+                hint: String::new(),
+            },
+            5,
+        ),
+        (
+            CliError::InputFileError {
+                path: "mnemonic.txt".to_string(),
+                message: String::new(),
+                hint: String::new(),
+            },
+            6,
+        ),
+        (
+            CliError::EnvVarNotSet {
+                name: "BIP39_PASSPHRASE".to_string(),
+            },
+            6,
+        ),
+        (CliError::InvalidMnemonic(bip39::Error::InvalidChecksum), 7),
+        (CliError::MnemonicError(bip39::Error::BadWordCount(11)), 7),
+        (
+            CliError::WeakPassphrase {
+                score: 0.2,
+                threshold: 0.6,
+            },
+            8,
+        ),
+        (
+            CliError::WeakEntropy {
+                score: 0.0,
+                threshold: 0.5,
+            },
+            8,
+        ),
+        (CliError::MnemonicsDiffer { differences: 1 }, 9),
+        (CliError::UnsupportedKdfRounds { rounds: 4096 }, 1),
+    ];
+
+    for (err, code) in cases {
+        assert_eq!(err.exit_code(), code, "{err:?}");
+    }
+}
+
+#[test]
+fn test_to_json_flattens_variant_fields() {
+    let err = CliError::InvalidWordCount {
+        actual: 11,
+        expected: vec![12, 15, 18, 21, 24],
+        hint: "Add a word".to_string(),
+    };
+    let json = err.to_json();
+
+    assert_eq!(json["error_type"], "InvalidWordCount");
+    assert_eq!(json["actual"], 11);
+    assert_eq!(json["expected"], serde_json::json!([12, 15, 18, 21, 24]));
+    assert_eq!(json["hint"], "Add a word");
+    assert_eq!(json["exit_code"], 3);
+    assert_eq!(json["description"], err.to_string());
+}
+
+#[test]
+fn test_to_json_shape_of_every_variant() {
+    let cases: Vec<(CliError, &str, &[&str])> = vec![
+        (
+            CliError::InvalidEntropyLength {
+                actual: 30,
+                expected: vec![32],
+                hint: String::new(),
+            },
+            "InvalidEntropyLength",
+            &["actual", "expected", "hint"],
+        ),
+        (
+            CliError::InvalidHexString {
+                message: String::new(),
+                position: Some(3),
+                hint: String::new(),
+            },
+            "InvalidHexString",
+            &["message", "position", "hint"],
+        ),
+        (
+            CliError::InvalidWordCount {
+                actual: 11,
+                expected: vec![12],
+                hint: String::new(),
+            },
+            "InvalidWordCount",
+            &["actual", "expected", "hint"],
+        ),
+        (
+            CliError::UnexpectedWordCount {
+                actual: 12,
+                expected: 24,
+            },
+            "UnexpectedWordCount",
+            &["actual", "expected"],
+        ),
+        (
+            CliError::InvalidWord {
+                word: "abandn".to_string(),
+                position: 1,
+                suggestions: vec!["abandon".to_string()],
+            },
+            "InvalidWord",
+            &["word", "position", "suggestions"],
+        ),
+        (
+            CliError::InvalidWordIndex {
+                value: "2048".to_string(),
+                position: None,
+            },
+            "InvalidWordIndex",
+            &["value", "position"],
+        ),
+        (
+            CliError::InvalidIndexGrid {
+                line: 2,
+                message: String::new(),
+            },
+            "InvalidIndexGrid",
+            &["line", "message"],
+        ),
+        (
+            CliError::MnemonicError(bip39::Error::BadWordCount(11)),
+            "MnemonicError",
+            &["message"],
+        ),
+        (
+            CliError::InvalidMnemonic(bip39::Error::InvalidChecksum),
+            "InvalidMnemonic",
+            &["message"],
+        ),
+        (
+            CliError::ElectrumSeed {
+                seed_type: "segwit".to_string(),
+            },
+            "ElectrumSeed",
+            &["seed_type"],
+        ),
+        (
+            CliError::LanguageDetectionFailed {
+                candidates: vec!["English".to_string()],
+            },
+            "LanguageDetectionFailed",
+            &["candidates"],
+        ),
+        (
+            CliError::HexDecodeError(hex::FromHexError::OddLength),
+            "HexDecodeError",
+            &["message"],
+        ),
+        (
+            CliError::DerivationError(bitcoin::bip32::Error::CannotDeriveFromHardenedKey),
+            "DerivationError",
+            &["message"],
+        ),
+        (
+            CliError::EnvVarNotSet {
+                name: "BIP39_PASSPHRASE".to_string(),
+            },
+            "EnvVarNotSet",
+            &["name"],
+        ),
+        (
+            CliError::InputFileError {
+                path: "in.txt".to_string(),
+                message: String::new(),
+                hint: String::new(),
+            },
+            "InputFileError",
+            &["path", "message", "hint"],
+        ),
+        (
+            CliError::OutputFileError {
+                path: "out.txt".to_string(),
+                message: String::new(),
+                hint: String::new(),
+            },
+            "OutputFileError",
+            &["path", "message", "hint"],
+        ),
+        (
+            CliError::SelfTestFailed {
+                mean: 0.5,
+                threshold: 0.9,
+            },
+            "SelfTestFailed",
+            &["mean", "threshold"],
+        ),
+        (
+            CliError::MnemonicsDiffer { differences: 1 },
+            "MnemonicsDiffer",
+            &["differences"],
+        ),
+        (
+            CliError::VerificationMismatch {
+                target: "seed".to_string(),
+            },
+            "VerificationMismatch",
+            &["target"],
+        ),
+        (
+            CliError::UnsupportedKdfRounds { rounds: 4096 },
+            "UnsupportedKdfRounds",
+            &["rounds"],
+        ),
+        (
+            CliError::SecureInputError {
+                message: String::new(),
+                hint: String::new(),
+            },
+            "SecureInputError",
+            &["message", "hint"],
+        ),
+        (
+            CliError::WeakPassphrase {
+                score: 0.2,
+                threshold: 0.6,
+            },
+            "WeakPassphrase",
+            &["score", "threshold"],
+        ),
+        (
+            CliError::BatchValidationFailed {
+                invalid: 1,
+                total: 2,
+            },
+            "BatchValidationFailed",
+            &["invalid", "total"],
+        ),
+        (
+            CliError::WeakEntropy {
+                score: 0.0,
+                threshold: 0.5,
+            },
+            "WeakEntropy",
+            &["score", "threshold"],
+        ),
+        (
+            CliError::AvoidWordsExhausted {
+                attempts: 1000,
+                excluded: 3,
+                distinct: false,
+            },
+            "AvoidWordsExhausted",
+            &["attempts", "excluded", "distinct"],
+        ),
+        (
+            CliError::ClipboardError {
+                message: String::new(),
+            },
+            "ClipboardError",
+            &["message"],
+        ),
+        (
+            CliError::WordListMismatch {
+                languages: vec!["English".to_string()],
+            },
+            "WordListMismatch",
+            &["languages"],
+        ),
+        (
+            CliError::ComplianceFailed {
+                failed: 1,
+                total: 24,
+            },
+            "ComplianceFailed",
+            &["failed", "total"],
+        ),
+        (
+            CliError::DecryptionFailed {
+                message: String::new(),
+            },
+            "DecryptionFailed",
+            &["message"],
+        ),
+        (CliError::SecretRefused, "SecretRefused", &[]),
+        (
+            CliError::RepeatedEntropy { draws: 0 },
+            "RepeatedEntropy",
+            &["draws"],
+        ),
+        (
+            CliError::UsageError {
+                message: String::new(),
+                hint: String::new(),
+            },
+            "UsageError",
+            &["message", "hint"],
+        ),
+        (CliError::NoCommandProvided, "NoCommandProvided", &[]),
+    ];
+
+    for (err, error_type, fields) in cases {
+        let json = err.to_json();
+        let object = json.as_object().unwrap();
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+
+        let mut expected = vec!["description", "error_type", "exit_code"];
+        expected.extend_from_slice(fields);
+        expected.sort_unstable();
+
+        assert_eq!(keys, expected, "{err:?}");
+        assert_eq!(json["error_type"], error_type);
+        assert_eq!(json["exit_code"], err.exit_code());
+    }
+}
@@ -1,5 +1,6 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
+use std::io::Write;
 
 #[test]
 fn test_cli_generate_12_words() {
@@ -30,6 +31,464 @@ fn test_cli_generate_24_words() {
     assert_eq!(mnemonic.split_whitespace().count(), 24);
 }
 
+#[test]
+fn test_cli_generate_strength_bits() {
+    for (bits, words) in [("128", 12), ("256", 24)] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["generate", "--strength", bits, "--quiet"]);
+
+        let output = cmd.assert().success();
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+        assert_eq!(stdout.split_whitespace().count(), words);
+    }
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--strength", "100"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("not a BIP39 strength"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--strength", "256", "--words", "24"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_cli_generate_dry_run() {
+    // This entropy would encode "abandon ... about"
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--show-entropy",
+            "--show-seed",
+            "--dry-run",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Words: 12"))
+        .stdout(predicate::str::contains("Show seed: yes"))
+        .stdout(predicate::str::contains("abandon").not())
+        .stdout(predicate::str::contains("00000000000000000000000000000000").not());
+
+    // Option resolution still runs, so a missing passphrase variable fails
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--words",
+            "12",
+            "--show-seed",
+            "--passphrase-env",
+            "BIP39_DRY_RUN_UNSET_VAR",
+            "--dry-run",
+        ])
+        .assert()
+        .code(6);
+}
+
+#[test]
+fn test_cli_generate_extra_entropy() {
+    let zeros = "00000000000000000000000000000000";
+    let generate = |source: &str, extra: &str| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["generate", "--quiet", "--entropy-source", source])
+            .args(["--extra-entropy", extra])
+            .assert()
+    };
+
+    // XOR with zeros is the identity, so the drawn entropy passes through unchanged
+    generate(&format!("hex:{zeros}"), zeros).success().stdout(predicate::str::diff(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+    ));
+    // ...while ff bytes flip every bit
+    generate(&format!("hex:{zeros}"), "ffffffffffffffffffffffffffffffff")
+        .success()
+        .stdout(predicate::str::diff(
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong\n",
+        ));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--words",
+            "12",
+            "--quiet",
+            "--extra-entropy",
+            zeros,
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| {
+            out.split_whitespace().count() == 12
+        }));
+
+    // The extra bytes must match the entropy length
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--words", "24", "--extra-entropy", zeros])
+        .assert()
+        .code(5)
+        .stderr(predicate::str::contains(
+            "--extra-entropy must be exactly as long",
+        ));
+}
+
+#[test]
+fn test_cli_validate_warn_duplicates() {
+    let validate = |mnemonic: &str| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["validate", "--quiet", "--warn-duplicates", mnemonic])
+            .assert()
+            .success()
+    };
+
+    validate(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    )
+    .stderr(predicate::str::contains("'abandon' appears 11 times"));
+
+    // Words repeated twice are common by chance and not reported
+    validate("legal winner thank year wave sausage worth useful legal winner thank yellow")
+        .stderr(predicate::str::contains("appears").not());
+}
+
+#[test]
+fn test_cli_input_is_canonicalized_before_processing() {
+    let run = |command: &str, mnemonic: &str| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args([command, "--quiet", mnemonic])
+            .assert()
+    };
+    let messy = "Abandon  abandon\tABANDON abandon abandon abandon abandon abandon abandon abandon abandon About";
+    let typo = "Abandon  abandon\tABANDN abandon abandon abandon abandon abandon abandon abandon abandon About";
+
+    for command in ["validate", "seed", "entropy"] {
+        run(command, messy).success();
+        run(command, typo)
+            .code(4)
+            .stderr(predicate::str::contains(
+                "Invalid word 'abandn' at position 3",
+            ))
+            .stderr(predicate::str::contains("abandon"));
+    }
+    run("entropy", messy).stdout("00000000000000000000000000000000\n");
+}
+
+#[test]
+fn test_cli_validate_expected_word_count() {
+    let validate = |expected: &str| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args([
+                "validate",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "--expected-word-count",
+                expected,
+            ])
+            .assert()
+    };
+
+    validate("24").code(3).stderr(predicate::str::contains(
+        "Mnemonic has 12 words, but 24 were expected",
+    ));
+    validate("12").success();
+}
+
+#[test]
+fn test_cli_json_errors() {
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "--json-errors",
+            "validate",
+            "--quiet",
+            "abandon abandon abandon",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["error_type"], "InvalidWordCount");
+    assert_eq!(error["actual"], 3);
+    assert_eq!(error["expected"], serde_json::json!([12, 15, 18, 21, 24]));
+    assert_eq!(error["exit_code"], 3);
+}
+
+#[test]
+fn test_cli_generate_analyze_entropy_refuses_compromised_entropy() {
+    // All-zero entropy is broken, not malformed: exit 8 like other weak entropy, not 5
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "--json-errors",
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--analyze-entropy",
+            "--quiet",
+        ])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(8));
+
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["error_type"], "WeakEntropy");
+    assert_eq!(error["threshold"], 0.1);
+}
+
+#[test]
+fn test_cli_generate_no_repeats() {
+    // About one 24-word mnemonic in eight repeats a word, so a few runs would catch a no-op
+    for _ in 0..8 {
+        let output = Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["generate", "--words", "24", "--no-repeats", "--quiet"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let phrase = String::from_utf8(output.stdout).unwrap();
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        let distinct: std::collections::HashSet<&str> = words.iter().copied().collect();
+        assert_eq!(words.len(), 24);
+        assert_eq!(distinct.len(), 24, "repeated word in: {phrase}");
+    }
+}
+
+#[test]
+fn test_cli_generate_count_unique() {
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate", "--words", "12", "--count", "100", "--unique", "--quiet",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    let distinct: std::collections::HashSet<&str> = lines.iter().copied().collect();
+    assert_eq!(lines.len(), 100);
+    assert_eq!(distinct.len(), 100);
+    assert!(lines
+        .iter()
+        .all(|line| line.split_whitespace().count() == 12));
+
+    // A batch has no single entropy, seed or commitment, and --unique only applies to a batch
+    for extra in [
+        &["--show-entropy"][..],
+        &["--raw"],
+        &["--encrypt-to", "seed.key"],
+        &["--hash-salt", "0102"],
+    ] {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["generate", "--words", "12", "--count", "2"])
+            .args(extra)
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--words", "12", "--unique"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_cli_generate_hash_output() {
+    let phrase =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let commitment = "b6a803aeeaec77d2daa000b1043bb5e4aa53ccbb3b49067886ee1fba9f763694";
+    let generate = || {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args([
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--hash-output",
+            "--hash-salt",
+            "0102",
+            "--quiet",
+        ]);
+        cmd
+    };
+
+    // Without a secret sink the mnemonic goes to stderr, never stdout
+    generate()
+        .assert()
+        .success()
+        .stdout(predicate::str::diff(format!("0102\t{commitment}\n")))
+        .stderr(predicate::str::contains(phrase));
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mnemonic.txt");
+    generate()
+        .arg("--output-file")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::diff(format!("0102\t{commitment}\n")))
+        .stderr(predicate::str::contains("abandon").not());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        format!("{phrase}\n")
+    );
+
+    // A random salt is drawn otherwise, so repeated runs commit differently
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--hash-output",
+            "--quiet",
+        ])
+        .output()
+        .unwrap();
+    let line = String::from_utf8(output.stdout).unwrap();
+    let (salt, hash) = line.trim_end().split_once('\t').unwrap();
+    assert_eq!(salt.len(), 64);
+    assert_ne!(hash, commitment);
+}
+
+#[test]
+fn test_cli_generate_explain() {
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--explain",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1. Entropy: the random input (128 bits, 16 bytes)\n00000000000000000000000000000000\n",
+        ))
+        .stdout(predicate::str::contains(
+            "2. Mnemonic: the entropy plus a 4-bit checksum, read as 12 11-bit word indices",
+        ))
+        .stdout(predicate::str::contains(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ))
+        .stdout(predicate::str::contains(
+            "3. Seed: PBKDF2-HMAC-SHA512 of the mnemonic and an empty passphrase (512 bits, 64 bytes)\n5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4\n",
+        ));
+
+    // --explain prints every secret, so it cannot honor --redact
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--explain", "--redact"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_cli_bench() {
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["bench", "--words", "24", "--iterations", "3", "--quiet"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let operations: Vec<&str> = stdout
+        .lines()
+        .map(|line| {
+            let (name, rate) = line.split_once('\t').unwrap();
+            assert!(rate.parse::<f64>().unwrap() > 0.0, "bad rate: {line}");
+            name
+        })
+        .collect();
+    assert_eq!(operations, ["generate", "validate", "seed", "entropy"]);
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["bench", "--iterations", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Iterations: 2"))
+        .stdout(predicate::str::contains("seed").and(predicate::str::contains("ops/sec")));
+}
+
+#[test]
+fn test_cli_generate_deterministic() {
+    use sha2::{Digest, Sha256};
+
+    let generate = |seed: &str| {
+        let output = Command::cargo_bin("bip39")
+            .unwrap()
+            .args([
+                "generate",
+                "--words",
+                "12",
+                "--deterministic",
+                seed,
+                "--quiet",
+            ])
+            .assert()
+            .success()
+            // The warning survives --quiet
+            .stderr(predicate::str::contains("NOT secure"));
+        String::from_utf8(output.get_output().stdout.clone()).unwrap()
+    };
+
+    let first = generate("demo");
+    assert_eq!(first, generate("demo"));
+    assert_ne!(first, generate("demo2"));
+    assert_eq!(first.split_whitespace().count(), 12);
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--words", "12", "--deterministic", "demo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("deterministic test entropy"))
+        .stdout(predicate::str::contains("OsRng").not());
+
+    // The entropy is the leading 16 bytes of SHA-256("demo")
+    let entropy = hex::encode(&Sha256::digest(b"demo")[..16]);
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", &entropy, "--quiet"])
+        .assert()
+        .success()
+        .stdout(first);
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "generate",
+            "--deterministic",
+            "demo",
+            "--entropy-source",
+            "os",
+        ])
+        .assert()
+        .failure()
+        .code(2);
+}
+
 #[test]
 fn test_cli_generate_with_entropy_and_seed() {
     let mut cmd = Command::cargo_bin("bip39").unwrap();
@@ -172,6 +631,53 @@ fn test_cli_from_entropy() {
     assert_eq!(mnemonic.split_whitespace().count(), 12);
 }
 
+#[test]
+fn test_cli_from_entropy_all_languages() {
+    let entropy = "00000000000000000000000000000000";
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", entropy, "--all-languages", "--quiet"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let lines: Vec<(&str, &str)> = stdout
+        .lines()
+        .map(|line| line.split_once('\t').expect("labeled line"))
+        .collect();
+    assert_eq!(lines.len(), 10);
+    assert!(lines.contains(&(
+        "english",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    )));
+    for (label, phrase) in &lines {
+        assert_eq!(phrase.split_whitespace().count(), 12, "{label}");
+        // Each labeled phrase must round-trip in its own language
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["validate", phrase, "--language", label, "--quiet"])
+            .assert()
+            .success();
+    }
+
+    // Labeled but human-readable without --quiet
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", entropy, "--all-languages"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("Chinese (Simplified):"));
+    assert!(stdout.contains("Language: all"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", entropy, "--all-languages", "-l", "french"])
+        .assert()
+        .failure()
+        .code(2);
+}
+
 #[test]
 fn test_cli_from_entropy_invalid_length() {
     let mut cmd = Command::cargo_bin("bip39").unwrap();
@@ -186,6 +692,36 @@ fn test_cli_from_entropy_invalid_length() {
         .stderr(predicate::str::contains("Invalid entropy length"));
 }
 
+#[test]
+fn test_cli_from_entropy_pad_entropy() {
+    let short = "000000000000000000000000000000"; // 30 hex characters
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", short, "--quiet"])
+        .assert()
+        .code(5);
+
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", short, "--pad-entropy", "right", "--quiet"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let phrase = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(phrase.split_whitespace().count(), 12);
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["validate", phrase.trim(), "--quiet"])
+        .assert()
+        .success();
+
+    // The warning is not silenced by --quiet
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("--pad-entropy added 2 zero hex digit(s) on the right"));
+    assert!(stderr.contains("Only 120 of the 128 entropy bits were supplied"));
+}
+
 #[test]
 fn test_cli_from_entropy_invalid_hex() {
     let mut cmd = Command::cargo_bin("bip39").unwrap();
@@ -303,3 +839,2729 @@ fn test_cli_different_languages() {
         assert_eq!(mnemonic.split_whitespace().count(), 12);
     }
 }
+
+#[test]
+fn test_cli_address_taproot_bip86_vector() {
+    // Test vector from BIP86 for m/86'/0'/0'/0/0
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "address",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--purpose", "86",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Path: m/86'/0'/0'/0/0"))
+        .stdout(predicate::str::contains(
+            "Internal key: cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115",
+        ))
+        .stdout(predicate::str::contains(
+            "Output key: a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c",
+        ))
+        .stdout(predicate::str::contains(
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+        ));
+}
+
+#[test]
+fn test_cli_address_native_segwit_bip84_vector() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "address",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--purpose", "84",
+        "--quiet",
+    ]);
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert_eq!(stdout.trim(), "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+}
+
+#[test]
+fn test_cli_validate_show_normalized() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "validate",
+        "1. Abandon 2. abandon 3. abandon 4. abandon 5. abandon 6. abandon 7. abandon 8. abandon 9. abandon 10. abandon 11. abandon 12. ABOUT",
+        "--show-normalized",
+        "--quiet",
+    ]);
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    );
+    assert_eq!(lines[1], "valid");
+}
+
+#[test]
+fn test_cli_validate_show_normalized_flags_modified_characters() {
+    let words = ["abandon"; 11].join(" ");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "validate",
+            &format!("{words}\u{a0}about"),
+            "--show-normalized",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Normalized input: {words} about\n"
+        )))
+        .stdout(predicate::str::contains("Modified: yes"))
+        .stdout(predicate::str::contains(
+            "position 88: U+00A0 non-ASCII space, read as a space",
+        ))
+        .stdout(predicate::str::contains("Valid BIP39 mnemonic"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["validate", &format!("{words} about"), "--show-normalized"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Modified: no"))
+        .stdout(predicate::str::contains("Suspicious").not());
+}
+
+#[test]
+fn test_cli_seed_passphrase_env() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--passphrase", "TREZOR", "--quiet"]);
+    let output = cmd.assert().success();
+    let seed_from_argv = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.env("BIP39_TEST_PASSPHRASE", "TREZOR");
+    cmd2.args([
+        "seed",
+        mnemonic,
+        "--passphrase-env",
+        "BIP39_TEST_PASSPHRASE",
+        "--quiet",
+    ]);
+    let output2 = cmd2.assert().success();
+    let seed_from_env = String::from_utf8(output2.get_output().stdout.clone()).unwrap();
+
+    assert_eq!(seed_from_argv, seed_from_env);
+}
+
+#[test]
+fn test_cli_passphrase_env_takes_precedence_over_argv() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--passphrase", "TREZOR", "--quiet"]);
+    let output = cmd.assert().success();
+    let expected = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.env("BIP39_TEST_PASSPHRASE", "TREZOR");
+    cmd2.args([
+        "seed",
+        mnemonic,
+        "--passphrase",
+        "ignored",
+        "--passphrase-env",
+        "BIP39_TEST_PASSPHRASE",
+        "--quiet",
+    ]);
+    let output2 = cmd2.assert().success();
+    let actual = String::from_utf8(output2.get_output().stdout.clone()).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_cli_passphrase_env_unset() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.env_remove("BIP39_UNSET_PASSPHRASE");
+    cmd.args([
+        "generate",
+        "--words",
+        "12",
+        "--show-seed",
+        "--passphrase-env",
+        "BIP39_UNSET_PASSPHRASE",
+        "--quiet",
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Environment variable 'BIP39_UNSET_PASSPHRASE' is not set",
+    ));
+}
+
+#[test]
+fn test_cli_analyze_passphrase_weak() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["analyze-passphrase", "password123", "--quiet"]);
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let score: f64 = stdout.trim().parse().unwrap();
+
+    assert!(score < 0.3, "weak passphrase scored {score}");
+}
+
+#[test]
+fn test_cli_analyze_passphrase_strong() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["analyze-passphrase", "xK9#mQ2$vL7@pR4!nT8&wZ5%", "--quiet"]);
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let score: f64 = stdout.trim().parse().unwrap();
+
+    assert!(score >= 0.8, "strong passphrase scored {score}");
+}
+
+#[test]
+fn test_cli_analyze_passphrase_report() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["analyze-passphrase", "password123"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Passphrase Strength Analysis"))
+        .stdout(predicate::str::contains("Entropy:"))
+        .stdout(predicate::str::contains("common patterns"));
+}
+
+#[test]
+fn test_cli_analyze_entropy_all_zeros() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "analyze-entropy",
+        "00000000000000000000000000000000",
+        "--quiet",
+    ]);
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert_eq!(stdout.trim(), "0.00");
+}
+
+#[test]
+fn test_cli_analyze_entropy_sequential() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["analyze-entropy", "000102030405060708090a0b0c0d0e0f"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Sequential patterns detected"))
+        .stdout(predicate::str::contains(
+            "Shannon entropy: 4.00 bits per byte",
+        ));
+}
+
+#[test]
+fn test_cli_analyze_entropy_random() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["analyze-entropy", "9e885d952ad362caeb4efe34a8e91bd2"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Entropy Quality Analysis"))
+        .stdout(predicate::str::contains("Sequential patterns").not())
+        .stdout(predicate::str::contains("all zeros").not());
+}
+
+#[test]
+fn test_cli_validate_bad_checksum_exit_status() {
+    // All words are valid, but the checksum of the final word is wrong
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "validate",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::diff("invalid\n"))
+        .stderr(predicate::str::contains("Mnemonic validation failed"));
+}
+
+#[test]
+fn test_cli_from_entropy_input_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "00000000000000000000000000000000").unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-entropy", "--quiet", "--input-file"])
+        .arg(file.path());
+
+    cmd.assert().success().stdout(predicate::str::diff(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+    ));
+}
+
+#[test]
+fn test_cli_validate_and_entropy_input_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(
+        file,
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n"
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--quiet", "--input-file"])
+        .arg(file.path());
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("valid\n"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args(["entropy", "--quiet", "--input-file"])
+        .arg(file.path());
+    cmd2.assert()
+        .success()
+        .stdout(predicate::str::diff("00000000000000000000000000000000\n"));
+}
+
+#[test]
+fn test_cli_seed_input_file_matches_argv() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "{mnemonic}").unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--quiet"]);
+    let output = cmd.assert().success();
+    let expected = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args(["seed", "--quiet", "--input-file"])
+        .arg(file.path());
+    cmd2.assert()
+        .success()
+        .stdout(predicate::str::diff(expected));
+}
+
+#[test]
+fn test_cli_input_file_too_large() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(&[b'a'; 5000]).unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-entropy", "--quiet", "--input-file"])
+        .arg(file.path());
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("limit 4096"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cli_generate_output_file_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mnemonic.txt");
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--quiet", "--output-file"])
+        .arg(&path);
+    cmd.assert().success().stdout(predicate::str::is_empty());
+
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.ends_with('\n'));
+    assert_eq!(contents.trim_end().split(' ').count(), 12);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cli_seed_output_file_contents() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("seed.txt");
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase", "TREZOR",
+        "--quiet",
+        "--output-file",
+    ])
+    .arg(&path);
+    cmd.assert().success();
+
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04\n"
+    );
+}
+
+#[test]
+fn test_cli_entropy_commands_honor_output_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let path = dir.path().join("mnemonic.txt");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "from-entropy",
+            "00000000000000000000000000000000",
+            "--quiet",
+        ])
+        .arg("--output-file")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        format!("{mnemonic}\n")
+    );
+
+    let path = dir.path().join("entropy.txt");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic, "--quiet"])
+        .arg("--output-file")
+        .arg(&path)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "00000000000000000000000000000000\n"
+    );
+}
+
+#[test]
+fn test_cli_output_file_refuses_overwrite() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--quiet", "--output-file"])
+        .arg(file.path());
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("file already exists"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args([
+        "generate",
+        "--words",
+        "12",
+        "--quiet",
+        "--force",
+        "--output-file",
+    ])
+    .arg(file.path());
+    cmd2.assert().success();
+    assert_eq!(
+        std::fs::read_to_string(file.path())
+            .unwrap()
+            .split_whitespace()
+            .count(),
+        12
+    );
+}
+
+#[test]
+fn test_cli_assume_yes_confirms_overwrite() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    // No terminal to confirm on, so the overwrite is refused
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--quiet", "--output-file"])
+        .arg(file.path());
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains("--assume-yes"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "-y",
+        "generate",
+        "--words",
+        "12",
+        "--quiet",
+        "--output-file",
+    ])
+    .arg(file.path());
+    cmd.assert().success().stdout(predicate::str::is_empty());
+    let written = std::fs::read_to_string(file.path()).unwrap();
+    assert_eq!(written.split_whitespace().count(), 12);
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", "--secure-input", "--assume-yes"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("requires an interactive terminal"));
+}
+
+#[test]
+fn test_cli_words_prefix_unambiguous() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["words", "--prefix", "abou"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("about"))
+        .stdout(predicate::str::contains("Prefix is unambiguous"));
+}
+
+#[test]
+fn test_cli_words_index() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["words", "--index", "3", "--quiet"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("about\n"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args(["words", "--index", "2048", "--quiet"]);
+    cmd2.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid word index: 2048"));
+}
+
+#[test]
+fn test_cli_validate_checksum_only() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "validate",
+        "--checksum-only",
+        "--quiet",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("valid\n"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args([
+        "validate",
+        "--checksum-only",
+        "--quiet",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+    ]);
+    cmd2.assert()
+        .failure()
+        .stdout(predicate::str::diff("invalid\n"))
+        .stderr(predicate::str::contains("invalid checksum"));
+}
+
+#[test]
+fn test_cli_validate_stdin_list() {
+    let input = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n\
+                 \n\
+                 abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon\n\
+                 legal winner thank year wave sausage worth useful legal winner thank yellow\n";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list"]).write_stdin(input);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("1: valid"))
+        .stdout(predicate::str::contains("3: invalid"))
+        .stdout(predicate::str::contains("4: valid"))
+        .stdout(predicate::str::contains("2: ").not())
+        .stdout(predicate::str::contains("Checked: 3"))
+        .stdout(predicate::str::contains("Invalid: 1"))
+        .stderr(predicate::str::contains(
+            "1 of 3 mnemonics failed validation",
+        ));
+}
+
+#[test]
+fn test_cli_validate_stdin_list_word_range() {
+    let input = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n\
+                 abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art\n";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list", "--min-words", "24"])
+        .write_stdin(input);
+    cmd.assert()
+        .failure()
+        .code(7)
+        .stdout(predicate::str::contains(
+            "1: policy-invalid (12 words; policy allows at least 24 words)",
+        ))
+        .stdout(predicate::str::contains("2: valid"))
+        .stdout(predicate::str::contains("Outside word range: 1"))
+        .stderr(predicate::str::contains(
+            "1 of 2 mnemonics failed validation",
+        ));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list", "--quiet", "--max-words", "12"])
+        .write_stdin(input);
+    cmd.assert().failure().stdout(predicate::str::diff(
+        "1: valid\n2: policy-invalid (24 words; policy allows at most 12 words)\n",
+    ));
+
+    // An empty range is a usage error before any input is read
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "validate",
+        "--stdin-list",
+        "--min-words",
+        "24",
+        "--max-words",
+        "12",
+    ])
+    .write_stdin(input);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "--min-words 24 is greater than --max-words 12",
+        ));
+}
+
+#[test]
+fn test_cli_validate_stdin_list_all_valid() {
+    let input = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list", "--quiet"])
+        .write_stdin(input);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("1: valid\n"));
+}
+
+#[test]
+fn test_cli_validate_stdin_list_null_delimited() {
+    // Records may contain newlines and tabs; only NUL separates them
+    let input = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\0\
+                 legal\twinner thank year wave sausage\nworth useful legal winner thank yellow\0";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list", "--null", "--quiet"])
+        .write_stdin(input);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("1: valid\n2: valid\n"));
+
+    // Read line by line, the same input is split into different records
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--stdin-list", "--quiet"])
+        .write_stdin(input);
+    cmd.assert().failure();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--null", "abandon"]);
+    cmd.assert().failure().code(2);
+}
+
+#[test]
+fn test_cli_deep_requires_security_check() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["--deep"]);
+
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_cli_seed_weak_passphrase_rejected() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase",
+        "abc",
+        "--analyze-passphrase",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Passphrase strength too low"))
+        .stderr(predicate::str::contains("below the required 0.60"));
+}
+
+#[test]
+fn test_cli_seed_weak_passphrase_override() {
+    for flags in [
+        vec!["--no-passphrase-check"],
+        vec!["--min-passphrase-score", "0.0"],
+    ] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args([
+            "seed",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "--passphrase",
+            "abc",
+            "--analyze-passphrase",
+            "--quiet",
+        ])
+        .args(&flags);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::is_match("^[0-9a-f]{128}\n$").unwrap());
+    }
+}
+
+#[test]
+fn test_cli_min_passphrase_score_out_of_range() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--min-passphrase-score",
+        "1.5",
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "score must be between 0.0 and 1.0",
+    ));
+}
+
+#[test]
+fn test_cli_seed_passphrase_nfkd_equivalence() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mut seeds = Vec::new();
+
+    // Precomposed U+00E9 vs "e" + combining acute accent U+0301
+    for passphrase in ["caf\u{e9}", "cafe\u{301}"] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["seed", mnemonic, "--passphrase", passphrase, "--quiet"]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        seeds.push(output);
+    }
+    assert_eq!(seeds[0], seeds[1]);
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        mnemonic,
+        "--passphrase",
+        "caf\u{e9}",
+        "--show-normalized",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Passphrase bytes (UTF-8): 63616665cc81",
+        ))
+        .stdout(predicate::str::contains(
+            "Normalized mnemonic (NFKD): abandon abandon",
+        ));
+}
+
+#[test]
+fn test_cli_seed_show_normalized_quiet_is_unchanged() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase",
+        "TREZOR",
+        "--show-normalized",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04\n",
+        ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cli_memory_lock_keeps_output() {
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "--memory-lock",
+            "seed",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "--passphrase",
+            "TREZOR",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::diff(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04\n",
+        ));
+    // Locking either works silently or warns once
+    let stderr = String::from_utf8(output.get_output().stderr.clone()).unwrap();
+    assert!(stderr.is_empty() || stderr.matches("could not lock secret memory").count() == 1);
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "--memory-lock",
+            "generate",
+            "--words",
+            "24",
+            "--show-entropy",
+            "--show-seed",
+            "--quiet",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_cli_seed_language_auto() {
+    let french = "implorer visage sonnette voyage véloce pourpre volaille tribunal implorer visage sonnette voyelle";
+    let seed = |language: &str| {
+        let output = Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["seed", french, "--language", language, "--quiet"])
+            .assert()
+            .success();
+        String::from_utf8(output.get_output().stdout.clone()).unwrap()
+    };
+    assert_eq!(seed("auto"), seed("french"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["seed", french, "--language", "auto"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Language: French (detected)"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["seed", "abandon abandon zzz", "--language", "auto"])
+        .assert()
+        .code(7)
+        .stderr(predicate::str::contains(
+            "Could not detect the mnemonic's language",
+        ));
+}
+
+#[test]
+fn test_cli_seed_explain_shows_salt_and_iterations() {
+    let explain = |extra: &[&str]| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args([
+                "seed",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "--passphrase",
+                "TREZOR",
+                "--explain",
+            ])
+            .args(extra)
+            .assert()
+            .success()
+            // The seed itself is unchanged
+            .stdout(predicate::str::contains(
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ))
+            .stdout(predicate::str::contains("Iterations: 2048"))
+            .stdout(predicate::str::contains("HMAC: HMAC-SHA512"))
+    };
+
+    // Masked without a hint of the passphrase's length
+    explain(&[])
+        .stdout(predicate::str::contains(
+            "Salt: \"mnemonic\" + NFKD(passphrase) (hidden; --reveal shows it)",
+        ))
+        .stdout(predicate::str::contains("TREZOR").not())
+        .stdout(predicate::str::contains("14 bytes").not());
+    explain(&["--reveal"]).stdout(predicate::str::contains(
+        "Salt: \"mnemonicTREZOR\" (14 bytes",
+    ));
+}
+
+#[test]
+fn test_cli_validate_detects_electrum_seed() {
+    // Electrum standard and segwit seeds from Electrum's own test suite
+    for (seed, seed_type) in [
+        (
+            "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+            "standard",
+        ),
+        (
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            "segwit",
+        ),
+    ] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["validate", seed]);
+
+        cmd.assert()
+            .failure()
+            .stdout(predicate::str::contains("Invalid BIP39 mnemonic"))
+            .stderr(predicate::str::contains(format!(
+                "looks like an Electrum {seed_type} seed"
+            )));
+    }
+}
+
+#[test]
+fn test_cli_validate_bad_checksum_has_no_electrum_hint() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "validate",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid checksum"))
+        .stderr(predicate::str::contains("Electrum").not());
+}
+
+#[test]
+fn test_cli_generate_entropy_source_hex() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+        "--quiet",
+    ]);
+
+    cmd.assert().success().stdout(predicate::str::diff(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+    ));
+}
+
+#[test]
+fn test_cli_generate_entropy_source_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("entropy.hex");
+    std::fs::write(&path, "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--quiet", "--entropy-source"])
+        .arg(format!("file:{}", path.display()));
+
+    cmd.assert().success().stdout(predicate::str::diff(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow\n",
+    ));
+}
+
+#[test]
+fn test_cli_generate_entropy_source_word_count_mismatch() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--words",
+        "24",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "--words 24 needs 64 hex characters",
+    ));
+}
+
+#[test]
+fn test_cli_seed_format_round_trip() {
+    use base64::Engine;
+
+    let expected = hex::decode(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    )
+    .unwrap();
+
+    for format in ["hex", "base64", "base58"] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args([
+            "seed",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "--passphrase",
+            "TREZOR",
+            "--seed-format",
+            format,
+            "--quiet",
+        ]);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        let encoded = String::from_utf8(output).unwrap();
+        let encoded = encoded.trim_end();
+
+        let decoded = match format {
+            "hex" => hex::decode(encoded).unwrap(),
+            "base64" => base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .unwrap(),
+            _ => bs58::decode(encoded).into_vec().unwrap(),
+        };
+        assert_eq!(decoded, expected, "round trip failed for {format}");
+    }
+}
+
+#[test]
+fn test_cli_generate_show_seed_base64() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+        "--show-seed",
+        "--seed-format",
+        "base64",
+        "--quiet",
+    ]);
+
+    // 64 bytes encode to 88 base64 characters with padding
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match("\n[A-Za-z0-9+/]{86}==\n$").unwrap());
+}
+
+#[test]
+fn test_cli_seed_kdf_rounds() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--kdf-rounds", "4096", "--quiet"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unsupported KDF rounds: 4096"))
+        .stderr(predicate::str::contains("BIP32"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args(["seed", mnemonic, "--kdf-rounds", "2048", "--quiet"]);
+    cmd2.assert()
+        .success()
+        .stdout(predicate::str::is_match("^[0-9a-f]{128}\n$").unwrap());
+}
+
+#[test]
+fn test_cli_seed_cardano_icarus_vectors() {
+    // CIP-3 Icarus test vectors
+    let mnemonic =
+        "eight country switch draw meat scout mystery blade tip drift useless good keep usage title";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--scheme", "cardano-icarus", "--quiet"]);
+    cmd.assert().success().stdout(
+        "c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620\n",
+    );
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "seed",
+        mnemonic,
+        "--scheme",
+        "cardano-icarus",
+        "--passphrase",
+        "foo",
+        "--no-passphrase-check",
+        "--quiet",
+    ]);
+    cmd.assert().success().stdout(
+        "70531039904019351e1afb361cd1b312a4d0565d4ff9f8062d38acf4b15cce41d7b5738d9c893feea55512a3004acb0d222c35d3e3d5cde943a15a9824cbac59443cf67e589614076ba01e354b1a432e0e6db3b59e37fc56b5fb0222970a010e\n",
+    );
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--scheme", "cardano-icarus"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Cardano Master Key (Icarus)"))
+        .stdout(predicate::str::contains("Output: 768 bits (96 bytes)"))
+        .stdout(predicate::str::contains("Not a BIP39 seed"));
+}
+
+#[test]
+fn test_cli_seed_profile_reports_on_stderr_only() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--quiet"]);
+    let plain = cmd.assert().success().stderr(predicate::str::is_empty());
+    let plain_stdout = plain.get_output().stdout.clone();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--quiet", "--profile"]);
+    let profiled = cmd.assert().success();
+    assert_eq!(profiled.get_output().stdout, plain_stdout);
+
+    let stderr = String::from_utf8(profiled.get_output().stderr.clone()).unwrap();
+    for stage in ["normalization", "parsing", "to_seed (PBKDF2)"] {
+        let line = stderr
+            .lines()
+            .find(|line| line.contains(&format!("{stage}: ")))
+            .unwrap_or_else(|| panic!("no timing line for {stage} in {stderr}"));
+        assert!(line.ends_with(" ms"));
+    }
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+        "--seed-only",
+        "--profile",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff(
+            String::from_utf8(plain_stdout).unwrap(),
+        ))
+        .stderr(predicate::str::contains("to_seed (PBKDF2): "));
+}
+
+#[test]
+fn test_cli_fingerprint_known_vector() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "fingerprint",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("73c5da0a\n"));
+}
+
+#[test]
+fn test_cli_fingerprint_changes_with_passphrase() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "fingerprint",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase",
+        "TREZOR",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Passphrase: Used"))
+        .stdout(predicate::str::contains("73c5da0a").not());
+}
+
+#[test]
+fn test_cli_seed_encrypt_to_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = dir.path().join("seed.key");
+    let other_key = dir.path().join("other.key");
+    let encrypted = dir.path().join("seed.enc");
+    std::fs::write(&key, [0x42; 32]).unwrap();
+    std::fs::write(&other_key, [0x24; 32]).unwrap();
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "seed",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "--passphrase",
+            "TREZOR",
+            "--quiet",
+            "--encrypt-to",
+        ])
+        .arg(&key)
+        .arg("--output-file")
+        .arg(&encrypted)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    let contents = std::fs::read_to_string(&encrypted).unwrap();
+    assert!(contents.starts_with("bip39-seed-v1:"));
+    assert!(!contents.contains("c55257c3"));
+
+    let decrypt = |key: &std::path::Path| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .arg("decrypt")
+            .arg(&encrypted)
+            .arg("--keyfile")
+            .arg(key)
+            .arg("--quiet")
+            .assert()
+    };
+    decrypt(&key).success().stdout(predicate::str::diff(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04\n",
+    ));
+    decrypt(&other_key)
+        .code(9)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("did not authenticate"));
+}
+
+#[test]
+fn test_cli_encrypt_to_usage_rules() {
+    let dir = tempfile::tempdir().unwrap();
+    let key = dir.path().join("seed.key");
+    std::fs::write(&key, [0x42; 32]).unwrap();
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // --output-file is global, so it may also come before the subcommand
+    let encrypted = dir.path().join("seed.enc");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .arg("--output-file")
+        .arg(&encrypted)
+        .args(["seed", mnemonic, "--quiet", "--encrypt-to"])
+        .arg(&key)
+        .assert()
+        .success();
+    assert!(std::fs::read_to_string(&encrypted)
+        .unwrap()
+        .starts_with("bip39-seed-v1:"));
+
+    let generated = dir.path().join("generated.enc");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .arg("--output-file")
+        .arg(&generated)
+        .args(["generate", "--words", "12", "--seed-only", "--encrypt-to"])
+        .arg(&key)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(std::fs::read_to_string(&generated)
+        .unwrap()
+        .starts_with("bip39-seed-v1:"));
+
+    // Ciphertext only ever goes to --output-file
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["seed", mnemonic, "--encrypt-to"])
+        .arg(&key)
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("Seed Generation").not())
+        .stderr(predicate::str::contains("--output-file, which is missing"));
+
+    // The mnemonic would be printed in the clear beside the encrypted seed
+    for extra in [&[][..], &["--numbered"], &["--hash-output"]] {
+        let unused = dir.path().join("unused.enc");
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .arg("--output-file")
+            .arg(&unused)
+            .args(["generate", "--words", "12", "--encrypt-to"])
+            .arg(&key)
+            .args(extra)
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("needs --seed-only"));
+        assert!(!unused.exists());
+    }
+}
+
+#[test]
+fn test_cli_require_tty_refuses_piped_secrets() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    // assert_cmd captures stdout through a pipe, so it is never a terminal here
+    let run = |args: &[&str]| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .arg("--require-tty")
+            .args(args)
+            .assert()
+    };
+
+    run(&["generate", "--words", "12", "--quiet"])
+        .code(6)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("the output is not a terminal"));
+    // Without a sink, --hash-output falls back to stderr, which is captured too
+    run(&["generate", "--words", "12", "--hash-output", "--quiet"])
+        .code(6)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("writing the mnemonic to stderr").not())
+        .stderr(predicate::str::contains("the output is not a terminal"));
+    run(&["seed", mnemonic, "--quiet"])
+        .code(6)
+        .stdout(predicate::str::is_empty());
+    run(&["entropy", mnemonic, "--quiet"]).code(6);
+
+    // Results that are not secrets still print
+    run(&["validate", mnemonic, "--quiet"])
+        .success()
+        .stdout("valid\n");
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mnemonic.txt");
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "--require-tty",
+            "generate",
+            "--words",
+            "12",
+            "--quiet",
+            "--output-file",
+        ])
+        .arg(&path)
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(&path)
+            .unwrap()
+            .split_whitespace()
+            .count(),
+        12
+    );
+}
+
+#[test]
+fn test_cli_seed_to_xprv() {
+    // Seed of the all-"abandon ... about" mnemonic; root key from the BIP84 test vectors
+    let seed = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+    let seed_to_xprv = |seed: &str, extra: &[&str]| {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["seed-to-xprv", "--seed", seed])
+            .args(extra)
+            .assert()
+    };
+
+    seed_to_xprv(seed, &["--quiet"]).success().stdout(predicate::str::diff(
+        "xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVpxR86QEC8w35uxmGoggxtQTPvfUu\n",
+    ));
+    seed_to_xprv(seed, &["--network", "testnet"])
+        .success()
+        .stdout(predicate::str::contains("Fingerprint: 73c5da0a"))
+        .stdout(predicate::str::contains("Xpub: tpub"))
+        .stdout(predicate::str::contains("\ntprv"));
+
+    seed_to_xprv(&seed[..64], &["--quiet"])
+        .code(5)
+        .stderr(predicate::str::contains(
+            "Seed must be 128 hex characters, got 64",
+        ));
+}
+
+#[test]
+fn test_cli_info_summarizes_mnemonic() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["info", mnemonic])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Words: 12"))
+        .stdout(predicate::str::contains("Language: English (detected)"))
+        .stdout(predicate::str::contains("Entropy: 128 bits"))
+        .stdout(predicate::str::contains("Master fingerprint: 73c5da0a"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["info", mnemonic, "--quiet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::diff("12\tEnglish\t128\t73c5da0a\tnone\n"));
+}
+
+#[test]
+fn test_cli_info_reads_passphrase_from_env_and_file() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let expected = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["info", mnemonic, "--passphrase", "TREZOR", "--quiet"])
+        .output()
+        .unwrap();
+    assert!(expected.status.success());
+    assert!(String::from_utf8_lossy(&expected.stdout).ends_with("\tused\n"));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .env("BIP39_INFO_PASSPHRASE", "TREZOR")
+        .args([
+            "info",
+            mnemonic,
+            "--quiet",
+            "--passphrase-env",
+            "BIP39_INFO_PASSPHRASE",
+        ])
+        .assert()
+        .success()
+        .stdout(expected.stdout.clone());
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "TREZOR").unwrap();
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["info", mnemonic, "--quiet", "--passphrase-file"])
+        .arg(file.path())
+        .assert()
+        .success()
+        .stdout(expected.stdout);
+}
+
+#[test]
+fn test_cli_strength_combines_mnemonic_and_passphrase() {
+    let effective_bits = |args: &[&str]| -> f64 {
+        let output = Command::cargo_bin("bip39")
+            .unwrap()
+            .arg("strength")
+            .args(args)
+            .arg("--quiet")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap()
+    };
+
+    let twelve =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let twenty_four = format!("{} art", ["abandon"; 23].join(" "));
+    let weak = effective_bits(&[twelve]);
+    let strong = effective_bits(&[&twenty_four, "--passphrase", "Wry-Otter-Quilt-7-Fjord!"]);
+    assert_eq!(weak, 128.0);
+    assert!(strong > 256.0, "{strong}");
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["strength", twelve])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Mnemonic: 12 words, 128 bits"))
+        .stdout(predicate::str::contains("Passphrase: none"))
+        .stdout(predicate::str::contains("Effective entropy: 128.0 bits"));
+}
+
+#[test]
+fn test_cli_verify_matching_seed() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "verify",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase",
+        "TREZOR",
+        "--expected-seed",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("match\n"));
+}
+
+#[test]
+fn test_cli_verify_mismatched_seed() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "verify",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--expected-seed",
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::diff("mismatch\n"))
+        .stderr(predicate::str::contains(
+            "does not reproduce the expected seed",
+        ));
+}
+
+#[test]
+fn test_cli_verify_reports_misspelled_word() {
+    // Same 1-based position and suggestions as validate, seed and entropy
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "verify",
+            "abandon abandon abandn abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "--expected-fingerprint",
+            "73c5da0a",
+            "--quiet",
+        ])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "Invalid word 'abandn' at position 3",
+        ))
+        .stderr(predicate::str::contains("abandon"));
+}
+
+#[test]
+fn test_cli_verify_fingerprint() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "verify",
+        mnemonic,
+        "--expected-fingerprint",
+        "73c5da0a",
+        "--quiet",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("match\n"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args([
+        "verify",
+        mnemonic,
+        "--expected-fingerprint",
+        "00000000",
+        "--quiet",
+    ]);
+    cmd2.assert()
+        .failure()
+        .stdout(predicate::str::diff("mismatch\n"));
+}
+
+#[test]
+fn test_cli_from_indices_reconstructs_mnemonic() {
+    for indices in ["0,0,0,0,0,0,0,0,0,0,0,3", "0 0 0 0 0 0 0 0 0 0 0 3"] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["from-indices", indices, "--quiet"]);
+
+        cmd.assert().success().stdout(predicate::str::diff(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+        ));
+    }
+}
+
+#[test]
+fn test_cli_from_indices_rejects_out_of_range() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-indices", "0,0,0,0,0,0,0,0,0,0,2048,3"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid word index: 2048"))
+        .stderr(predicate::str::contains("Error at position: 11"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args(["from-indices", "0,0,0,0,0,0,0,0,0,0,0,x"]);
+
+    cmd2.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid word index: x"))
+        .stderr(predicate::str::contains("Error at position: 12"));
+}
+
+#[test]
+fn test_cli_from_indices_grid_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "# pos index").unwrap();
+    for position in 1..=11 {
+        writeln!(file, "{position} 0").unwrap();
+    }
+    writeln!(file, "12. 3").unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-indices", "--quiet", "--grid-file"])
+        .arg(file.path());
+
+    cmd.assert().success().stdout(predicate::str::diff(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+    ));
+}
+
+#[test]
+fn test_cli_from_image() {
+    use sha2::{Digest, Sha256};
+
+    // 16x16 RGB "noise" from a fixed LCG, so the expected mnemonic is known
+    let mut state: u32 = 0x1234_5678;
+    let image = image::RgbImage::from_fn(16, 16, |_, _| {
+        image::Rgb(std::array::from_fn(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 24) as u8
+        }))
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("noise.png");
+    image.save(&path).unwrap();
+
+    let digest = Sha256::digest(image.as_raw());
+    let expected = bip39::Mnemonic::from_entropy(&digest[..16]).unwrap();
+
+    let from_image = || {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["from-image", "--words", "12", "--quiet"])
+            .arg(&path)
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "only as unpredictable as the photo",
+            ))
+    };
+    from_image().stdout(predicate::str::diff(format!("{expected}\n")));
+    from_image().stdout(predicate::str::diff(format!("{expected}\n")));
+
+    // 768 samples pack into 96 LSB bytes, short of the 4 x 32 needed for 24 words
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-image", "--lsb"])
+        .arg(&path)
+        .assert()
+        .code(6)
+        .stderr(predicate::str::contains("96 bytes of material, 128 needed"));
+}
+
+#[test]
+fn test_cli_from_indices_grid_file_errors_are_positioned() {
+    let grid_error = |grid: &str| {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "{grid}").unwrap();
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["from-indices", "--grid-file"])
+            .arg(file.path())
+            .assert()
+            .code(4)
+    };
+
+    grid_error("1 0\n2 0\n4 0\n")
+        .stderr(predicate::str::contains("Invalid index grid at line 3"))
+        .stderr(predicate::str::contains("expected position 3, found \"4\""));
+    grid_error("1 0\n\n2 0 7\n").stderr(predicate::str::contains("Invalid index grid at line 3"));
+    grid_error("1 0\n2 2048\n")
+        .stderr(predicate::str::contains("Invalid word index: 2048"))
+        .stderr(predicate::str::contains("Error at position: 2"));
+}
+
+#[test]
+fn test_cli_from_indices_bad_checksum() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-indices", "0,0,0,0,0,0,0,0,0,0,0,0"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid checksum"));
+}
+
+#[test]
+fn test_cli_hex_case_upper_round_trips() {
+    let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic, "--quiet", "--hex-case", "upper"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let entropy = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(entropy, format!("{}\n", "7F".repeat(16)));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", entropy.trim_end(), "--quiet"])
+        .assert()
+        .success()
+        .stdout(format!("{mnemonic}\n"));
+
+    // Seeds follow the same flag (official vector, passphrase "TREZOR")
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["seed", mnemonic, "--passphrase", "TREZOR", "--quiet"])
+        .args(["--hex-case", "upper"])
+        .assert()
+        .success()
+        .stdout(format!(
+            "{}\n",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607"
+                .to_uppercase()
+        ));
+}
+
+#[test]
+fn test_cli_entropy_as_dice() {
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "entropy",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "--as-dice",
+            "--quiet",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let rolls: Vec<u8> = String::from_utf8(output.stdout)
+        .unwrap()
+        .trim_end()
+        .bytes()
+        .map(|digit| digit - b'0')
+        .collect();
+    assert_eq!(rolls.len(), 50);
+    assert_eq!(
+        bip39_cli::security::dice_to_entropy(&rolls, 16),
+        Some(vec![0x7f; 16])
+    );
+}
+
+#[test]
+fn test_cli_entropy_show_indices() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "entropy",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--show-indices",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("0 0 0 0 0 0 0 0 0 0 0 3\n"));
+
+    let mut cmd2 = Command::cargo_bin("bip39").unwrap();
+    cmd2.args([
+        "entropy",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "--show-indices",
+    ]);
+
+    cmd2.assert()
+        .success()
+        .stdout(predicate::str::contains("Word Indices"))
+        .stdout(predicate::str::contains("12. yellow"));
+}
+
+#[test]
+fn test_cli_entropy_show_checksum() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // SHA-256 of 16 zero bytes starts with 0x37, so the checksum is 0011
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", mnemonic, "--show-checksum"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Bits: 4"))
+        .stdout(predicate::str::contains("Binary: 0011"))
+        .stdout(predicate::str::contains("Hex: 0x03"))
+        .stdout(predicate::str::contains("✓ Matches the checksum"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", mnemonic, "--show-checksum", "--quiet"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("00000000000000000000000000000000\n"));
+}
+
+#[test]
+fn test_cli_diff_identical() {
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["diff", mnemonic, mnemonic]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Mnemonics are identical"));
+}
+
+#[test]
+fn test_cli_diff_single_word() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "diff",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "legal winner thank year wave sausage worth useful legal window thank yellow",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("10. winner     window     ✗"))
+        .stdout(predicate::str::contains("1 word substitution(s)"))
+        .stderr(predicate::str::contains(
+            "Mnemonics differ at 1 position(s)",
+        ));
+}
+
+#[test]
+fn test_cli_diff_checksum_only() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "diff",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("only the checksum bits differ"));
+}
+
+#[test]
+fn test_cli_diff_mismatched_lengths() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "diff",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--quiet",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::diff("different\n"))
+        .stderr(predicate::str::contains(
+            "Mnemonics differ at 4 position(s)",
+        ));
+}
+
+#[test]
+fn test_cli_self_test_is_hidden_but_runs() {
+    let mut help = Command::cargo_bin("bip39").unwrap();
+    help.arg("--help");
+    help.assert()
+        .success()
+        .stdout(predicate::str::contains("self-test").not());
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["self-test", "--samples", "10", "--quiet"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match("^[01]\\.[0-9]{3}\n$").unwrap());
+}
+
+#[test]
+fn test_cli_from_entropy_warns_on_weak_entropy() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-entropy", "00000000000000000000000000000000"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("abandon abandon"))
+        .stderr(predicate::str::contains("entropy quality score is 0.00"))
+        .stderr(predicate::str::contains("all zeros"));
+
+    // --quiet drops the warning text but still prints the mnemonic
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        "00000000000000000000000000000000",
+        "--quiet",
+    ]);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Warning").not());
+}
+
+#[test]
+fn test_cli_from_entropy_min_entropy_score() {
+    // --quiet does not bypass the refusal
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        "00000000000000000000000000000000",
+        "--min-entropy-score",
+        "0.5",
+        "--quiet",
+    ]);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Entropy quality too low"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        "9e885d952ad362caeb4efe34a8e91bd2",
+        "--min-entropy-score",
+        "0.5",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Mnemonic from Entropy"))
+        .stderr(predicate::str::contains("Warning").not());
+}
+
+#[test]
+fn test_cli_byte_order_little_reverses_entropy() {
+    // BIP39 test vector 9e885d95..., with its bytes reversed
+    let mnemonic = "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic";
+    let reversed = "d21be9a834fe4eebca62d32a955d889e";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        reversed,
+        "--byte-order",
+        "little",
+        "--quiet",
+    ]);
+    cmd.assert().success().stdout(format!("{mnemonic}\n"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", mnemonic, "--byte-order", "little", "--quiet"]);
+    cmd.assert().success().stdout(format!("{reversed}\n"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", mnemonic, "--byte-order", "big", "--quiet"]);
+    cmd.assert()
+        .success()
+        .stdout("9e885d952ad362caeb4efe34a8e91bd2\n");
+}
+
+#[test]
+fn test_cli_byte_order_round_trip() {
+    let entropy = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    for byte_order in ["big", "little"] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["from-entropy", entropy, "--byte-order", byte_order, "-q"]);
+        let output = cmd.assert().success();
+        let mnemonic = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["entropy", mnemonic.trim(), "--byte-order", byte_order, "-q"]);
+        cmd.assert().success().stdout(format!("{entropy}\n"));
+    }
+}
+
+#[test]
+fn test_cli_from_entropy_numbered() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        "00000000000000000000000000000000",
+        "--numbered",
+    ]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    assert!(stdout.contains(" 1. abandon\n"));
+    assert!(stdout.contains("12. about"));
+    let numbered = stdout.lines().filter(|line| line.contains(". ")).count();
+    assert_eq!(numbered, 12);
+}
+
+#[test]
+fn test_cli_generate_columns_preserves_words() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "24", "--numbered", "--columns", "4"]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    // 24 words in 4 columns fill 6 rows, numbered down each column
+    let grid: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.contains(" 7. "))
+        .collect();
+    assert_eq!(grid.len(), 1);
+    assert!(grid[0].trim_start().starts_with("1. "));
+    for n in 1..=24 {
+        assert!(stdout.contains(&format!("{n:>2}. ")), "missing word {n}");
+    }
+}
+
+#[test]
+fn test_cli_layout_ignored_when_quiet() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "from-entropy",
+        "00000000000000000000000000000000",
+        "--numbered",
+        "--columns",
+        "3",
+        "--quiet",
+    ]);
+    cmd.assert().success().stdout(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about\n",
+    );
+}
+
+#[test]
+fn test_cli_seed_passphrase_file_matches_passphrase() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let passphrase = " correct horse  battery staple ";
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(file, "{passphrase}").unwrap();
+
+    let mut expected = Command::cargo_bin("bip39").unwrap();
+    expected.args(["seed", mnemonic, "--passphrase", passphrase, "--quiet"]);
+    let expected = expected.assert().success();
+    let expected_seed = String::from_utf8(expected.get_output().stdout.clone()).unwrap();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["seed", mnemonic, "--quiet", "--passphrase-file"])
+        .arg(file.path());
+    cmd.assert().success().stdout(expected_seed.clone());
+
+    // Only one trailing newline is stripped; whitespace inside is preserved
+    let mut trimmed = Command::cargo_bin("bip39").unwrap();
+    trimmed.args([
+        "seed",
+        mnemonic,
+        "--passphrase",
+        passphrase.trim(),
+        "--quiet",
+    ]);
+    let trimmed = trimmed.assert().success();
+    assert_ne!(trimmed.get_output().stdout, expected_seed.as_bytes());
+}
+
+#[test]
+fn test_cli_generate_passphrase_file() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    write!(file, "TREZOR").unwrap();
+
+    // BIP39 test vector 1 seed with passphrase "TREZOR"
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+        "--show-seed",
+        "--no-passphrase-check",
+        "--quiet",
+        "--passphrase-file",
+    ])
+    .arg(file.path());
+    cmd.assert().success().stdout(predicate::str::contains(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+    ));
+
+    let mut missing = Command::cargo_bin("bip39").unwrap();
+    missing.args([
+        "seed",
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "--passphrase-file",
+        "/nonexistent/passphrase.txt",
+    ]);
+    missing
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("/nonexistent/passphrase.txt"));
+}
+
+#[test]
+fn test_cli_secure_input_without_terminal_fails_fast() {
+    let started = std::time::Instant::now();
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", "--secure-input", "--input-timeout", "1"])
+        .write_stdin("");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("stdin is not a terminal"));
+
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+}
+
+#[test]
+fn test_cli_input_timeout_rejects_zero() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "analyze-passphrase",
+        "--secure-input",
+        "--input-timeout",
+        "0",
+    ]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("not a positive number of seconds"));
+}
+
+#[test]
+fn test_cli_exit_codes_by_error_category() {
+    let cases: [(&[&str], i32); 5] = [
+        (&["validate", "abandon abandon abandon"], 3),
+        (
+            &[
+                "validate",
+                "abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            ],
+            4,
+        ),
+        (&["from-entropy", "xyz"], 5),
+        (&["validate", "--input-file", "/nonexistent/mnemonic.txt"], 6),
+        (
+            &[
+                "validate",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+            ],
+            7,
+        ),
+    ];
+
+    for (args, code) in cases {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(args);
+        cmd.assert().code(code);
+    }
+
+    // Argument parsing errors keep the conventional usage status
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "13"]);
+    cmd.assert().code(2);
+}
+
+#[test]
+fn test_cli_generate_avoid_word_never_appears() {
+    // The first 100 English words, i.e. roughly 1 in 20 of the list
+    let avoided = &bip39::Language::English.word_list()[..100];
+
+    for _ in 0..30 {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["generate", "--words", "24", "--quiet"]);
+        for word in avoided {
+            cmd.args(["--avoid-word", word]);
+        }
+        let output = cmd.assert().success();
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+        assert_eq!(stdout.split_whitespace().count(), 24);
+        for word in stdout.split_whitespace() {
+            assert!(!avoided.contains(&word), "avoided word '{word}' generated");
+        }
+    }
+}
+
+#[test]
+fn test_cli_generate_avoid_word_errors() {
+    // Excluding half the list makes a clean 24-word mnemonic vanishingly rare
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "24"]);
+    for word in &bip39::Language::English.word_list()[..1024] {
+        cmd.args(["--avoid-word", word]);
+    }
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("after 1000 attempts"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--avoid-word", "abandn"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid word 'abandn'"));
+}
+
+#[test]
+fn test_cli_generate_seed_only() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "24", "--seed-only"]);
+    let output = cmd.assert().success().stderr(predicate::str::is_empty());
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let seed = stdout.strip_suffix('\n').unwrap();
+    assert_eq!(seed.len(), 128);
+    assert!(seed.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(stdout.lines().count(), 1, "only the seed is printed");
+
+    // BIP39 test vector 1 with passphrase "TREZOR"
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--entropy-source",
+        "hex:00000000000000000000000000000000",
+        "--seed-only",
+        "--passphrase",
+        "TREZOR",
+        "--no-passphrase-check",
+    ]);
+    cmd.assert().success().stdout(
+        "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04\n",
+    );
+}
+
+#[test]
+fn test_cli_generate_verbose_encoding_indices_match_word_list() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--words",
+        "24",
+        "--show-entropy",
+        "--verbose-encoding",
+    ]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("Checksum bits: 8"));
+
+    let word_list = bip39::Language::English.word_list();
+    let rows: Vec<Vec<&str>> = stdout
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .filter(|fields| fields.len() == 4 && fields[0].ends_with('.'))
+        .collect();
+    assert_eq!(rows.len(), 24);
+
+    for row in &rows {
+        let bits = row[1].replace('|', "");
+        let index: usize = row[2].parse().unwrap();
+        let word = row[3];
+        assert_eq!(word_list.iter().position(|w| *w == word), Some(index));
+        assert_eq!(usize::from_str_radix(&bits, 2).unwrap(), index);
+    }
+    assert!(rows[23][1].contains('|'), "checksum bits are marked");
+
+    // Without the flag the encoding table is not printed
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--show-entropy"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Word Encoding").not());
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--verbose-encoding"]);
+    cmd.assert().failure().code(2);
+}
+
+#[test]
+fn test_cli_generate_copy_to_clipboard() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--clipboard-timeout", "1"]);
+    cmd.assert().failure().code(2);
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--words",
+        "12",
+        "--copy",
+        "--clipboard-timeout",
+        "1",
+        "--quiet",
+    ]);
+
+    if arboard::Clipboard::new().is_err() {
+        // Headless: a clear error before anything is generated
+        cmd.assert()
+            .failure()
+            .code(6)
+            .stdout(predicate::str::is_empty())
+            .stderr(predicate::str::contains("Clipboard unavailable"));
+        return;
+    }
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Copied to clipboard"))
+        .stderr(predicate::str::contains("Clipboard cleared"));
+}
+
+#[test]
+fn test_cli_redact_hides_every_word() {
+    let word_list = bip39::Language::English.word_list();
+
+    for layout in [&[][..], &["--numbered", "--columns", "4"][..]] {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["generate", "--words", "24", "--redact", "--quiet"]);
+        cmd.args(layout);
+        let output = cmd.assert().success();
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+        let masked: Vec<&str> = stdout
+            .split_whitespace()
+            .filter(|token| !token.ends_with('.'))
+            .collect();
+        assert_eq!(masked.len(), 24, "word count preserved in {stdout}");
+        for token in masked {
+            assert!(!word_list.contains(&token), "'{token}' revealed");
+            assert!(token.chars().skip(1).all(|c| c == '*'));
+        }
+    }
+
+    let mnemonic =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", mnemonic, "--show-normalized", "--redact"]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Normalized input: a****** a****** a****** a****** a****** a****** a****** a****** a****** a****** a****** a****\n",
+    ));
+
+    // Neither the misspelled word nor suggestions close to it are shown
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", &mnemonic.replace("about", "abuot"), "--redact"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid word 'a****' at position 12",
+        ))
+        .stderr(predicate::str::contains("Did you mean").not());
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", mnemonic, "--redact", "--quiet"]);
+    cmd.assert()
+        .success()
+        .stdout(format!("0{}\n", "*".repeat(31)));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--redact", "--show-seed"]);
+    cmd.assert().failure().code(2);
+}
+
+#[test]
+fn test_cli_dump_word_list() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["dump", "--language", "english"]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2048);
+    assert_eq!(lines[0], "abandon");
+    assert_eq!(lines[2047], "zoo");
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["dump", "--with-index", "--hex-index"]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert_eq!(stdout.lines().nth(3), Some("0x003 about"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "dump",
+        "--format",
+        "json",
+        "--with-index",
+        "--language",
+        "french",
+    ]);
+    let output = cmd.assert().success();
+    let entries: serde_json::Value = serde_json::from_slice(&output.get_output().stdout).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert_eq!(entries.len(), 2048);
+    let french = bip39::Language::French.word_list();
+    for (index, entry) in entries.iter().enumerate() {
+        assert_eq!(entry["index"], index);
+        assert_eq!(entry["word"], french[index]);
+    }
+
+    // Hidden from --help, like self-test
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.arg("--help");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("dump").not());
+}
+
+#[test]
+fn test_cli_generate_entropy_histogram() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "24", "--show-entropy", "--histogram"]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+
+    let hex = stdout
+        .lines()
+        .find(|line| line.len() == 64 && line.chars().all(|c| c.is_ascii_hexdigit()))
+        .unwrap();
+    let counts: Vec<(char, usize)> = stdout
+        .lines()
+        .skip_while(|line| *line != "Nibble Histogram")
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let digit = fields.next()?.parse::<char>().ok()?;
+            let count = fields.next()?.parse::<usize>().ok()?;
+            Some((digit, count))
+        })
+        .collect();
+    assert_eq!(counts.len(), 16);
+    assert_eq!(
+        counts.iter().map(|(_, count)| count).sum::<usize>(),
+        hex.len()
+    );
+    for (digit, count) in counts {
+        assert_eq!(hex.matches(digit).count(), count, "digit {digit}");
+    }
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args([
+        "generate",
+        "--words",
+        "12",
+        "--show-entropy",
+        "--histogram",
+        "--quiet",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Histogram").not());
+}
+
+#[test]
+fn test_cli_words_file_round_trips_entropy() {
+    // Shuffle the English list with a fixed permutation (1021 is coprime to 2048)
+    let english = bip39::Language::English.word_list();
+    let shuffled: Vec<&str> = (0..2048).map(|i| english[i * 1021 % 2048]).collect();
+    let mut words_file = tempfile::NamedTempFile::new().unwrap();
+    writeln!(words_file, "{}", shuffled.join("\n")).unwrap();
+
+    let entropy = "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f";
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-entropy", entropy, "--quiet", "--words-file"])
+        .arg(words_file.path());
+    let output = cmd
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("NOT standard BIP39"));
+    let phrase = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let phrase = phrase.trim_end();
+    assert_eq!(phrase.split_whitespace().count(), 12);
+    assert_ne!(
+        phrase,
+        "legal winner thank year wave sausage worth useful legal winner thank yellow"
+    );
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["entropy", phrase, "--quiet", "--words-file"])
+        .arg(words_file.path());
+    cmd.assert().success().stdout(format!("{entropy}\n"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", phrase, "--quiet", "--words-file"])
+        .arg(words_file.path());
+    cmd.assert().success().stdout("valid\n");
+
+    // The same phrase is not a valid standard mnemonic
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", phrase, "--quiet"]);
+    cmd.assert().failure();
+
+    // Lists must hold exactly 2048 unique words
+    let mut duplicated = tempfile::NamedTempFile::new().unwrap();
+    writeln!(duplicated, "{}\nabandon", english[..2047].join("\n")).unwrap();
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["from-entropy", entropy, "--words-file"])
+        .arg(duplicated.path());
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains("'abandon' appears more than once"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cli_generate_out_fd_pipe() {
+    use std::io::Read;
+    use std::os::unix::io::FromRawFd;
+
+    let mut fds = [0; 2];
+    // SAFETY: fds has room for both ends; pipe() leaves them inheritable
+    assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+    let [read_fd, write_fd] = fds;
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--quiet", "--out-fd"])
+        .arg(write_fd.to_string());
+    let output = cmd.output().unwrap();
+
+    // SAFETY: both descriptors came from pipe() above and are owned here
+    let mut reader = unsafe { std::fs::File::from_raw_fd(read_fd) };
+    drop(unsafe { std::fs::File::from_raw_fd(write_fd) });
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty(), "nothing on stdout");
+    let mut mnemonic = String::new();
+    reader.read_to_string(&mut mnemonic).unwrap();
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    assert_eq!(words.len(), 12);
+    let phrase = words.join(" ");
+    assert!(bip39::Mnemonic::parse_in_normalized(bip39::Language::English, &phrase).is_ok());
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["generate", "--words", "12", "--out-fd", "1"]);
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains("standard streams"));
+}
+
+#[test]
+fn test_cli_languages_lists_all_ten() {
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.arg("languages");
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("chinese-simplified    Chinese (Simplified)"));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["languages", "--quiet"]);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let languages: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        languages,
+        [
+            "english",
+            "japanese",
+            "korean",
+            "spanish",
+            "chinese-simplified",
+            "chinese-traditional",
+            "french",
+            "italian",
+            "czech",
+            "portuguese",
+        ]
+    );
+
+    // Every listed value is accepted by --language
+    for language in languages {
+        let mut cmd = Command::cargo_bin("bip39").unwrap();
+        cmd.args(["words", "--index", "0", "--quiet", "--language", language]);
+        cmd.assert().success();
+    }
+}
+
+#[test]
+fn test_cli_language_short_codes() {
+    let run = |args: &[&str]| {
+        let output = Command::cargo_bin("bip39")
+            .unwrap()
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let generate = |language: &str| {
+        run(&[
+            "generate",
+            "--entropy-source",
+            "hex:00000000000000000000000000000000",
+            "--quiet",
+            "--language",
+            language,
+        ])
+    };
+
+    for (code, name) in [
+        ("ja", "japanese"),
+        ("zh-hans", "chinese-simplified"),
+        ("zh-hant", "chinese-traditional"),
+        ("pt", "portuguese"),
+    ] {
+        assert_eq!(generate(code), generate(name));
+    }
+
+    // Also through the auto-capable parser used by seed
+    let mnemonic = generate("japanese");
+    assert_eq!(
+        run(&["seed", mnemonic.trim_end(), "--quiet", "--language", "ja"]),
+        run(&[
+            "seed",
+            mnemonic.trim_end(),
+            "--quiet",
+            "--language",
+            "japanese"
+        ])
+    );
+}
+
+#[test]
+fn test_cli_suggestions_limit() {
+    let mnemonic = "lable abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", mnemonic]);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        r#"Did you mean one of: ["able", "cable", "table"]"#,
+    ));
+
+    let mut cmd = Command::cargo_bin("bip39").unwrap();
+    cmd.args(["validate", mnemonic, "--suggestions", "1"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(r#"Did you mean one of: ["able"]"#));
+}
+
+#[test]
+fn test_cli_max_edit_distance() {
+    let mnemonic = "abndnd abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // Too garbled for the default distance of 2
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["validate", mnemonic])
+        .assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains("Did you mean").not());
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["validate", mnemonic, "--max-edit-distance", "3"])
+        .assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            r#"Did you mean one of: ["abandon""#,
+        ));
+}
+
+#[test]
+fn test_cli_integrity() {
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["integrity", "--quiet"])
+        .assert()
+        .success()
+        .stdout("ok\n");
+
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .arg("integrity")
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert_eq!(stdout.matches('✓').count(), 10);
+    assert!(stdout.contains("2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda"));
+}
+
+#[test]
+fn test_cli_compliance_passes_all_vectors() {
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["compliance", "--quiet"])
+        .assert()
+        .success()
+        .stdout("ok\n");
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .arg("compliance")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Vectors: 24"))
+        .stdout(predicate::str::contains("Passed: 24"))
+        .stdout(predicate::str::contains("Failed: 0"));
+}
+
+#[test]
+fn test_cli_validate_verbose_reports_normalization() {
+    use unicode_normalization::UnicodeNormalization;
+
+    // "あおぞら" typed with a precomposed ぞ (U+305E) is NFC, not NFKD
+    let composed = "あいこくしん ".repeat(11) + "あお\u{305E}ら";
+    let nfkd: String = composed.nfkd().collect();
+    assert_ne!(composed, nfkd);
+
+    for (phrase, expected) in [
+        (&composed, "NFKD changed the input"),
+        (&nfkd, "input was already NFKD"),
+    ] {
+        Command::cargo_bin("bip39")
+            .unwrap()
+            .args(["validate", phrase, "--language", "japanese", "--verbose"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Word list: 2048 words"))
+            .stdout(predicate::str::contains("Script: Kana"))
+            .stdout(predicate::str::contains(expected));
+    }
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["validate", &nfkd, "-l", "japanese", "--verbose", "--quiet"])
+        .assert()
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn test_cli_raw_entropy_bytes() {
+    let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic, "--raw"])
+        .assert()
+        .success();
+    let stdout = &output.get_output().stdout;
+    assert_eq!(hex::encode(stdout), "7f".repeat(16));
+
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic, "--raw", "--byte-order", "little"])
+        .assert()
+        .success();
+    assert_eq!(output.get_output().stdout.len(), 16);
+
+    // generate: stdout carries only the entropy; the mnemonic goes to the file
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mnemonic.txt");
+    let output = Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--words", "12", "--deterministic", "demo"])
+        .args(["--show-entropy", "--raw", "--output-file"])
+        .arg(&path)
+        .assert()
+        .success();
+    let raw = output.get_output().stdout.clone();
+    assert_eq!(raw.len(), 16);
+
+    let mnemonic = std::fs::read_to_string(&path).unwrap();
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic.trim(), "--quiet"])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", hex::encode(&raw)));
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["generate", "--words", "12", "--raw"])
+        .assert()
+        .failure()
+        .code(2);
+}
+
+#[test]
+fn test_cli_output_prefix_suffix() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args([
+            "from-entropy",
+            "00000000000000000000000000000000",
+            "--quiet",
+        ])
+        .args(["--output-prefix", "BEGIN", "--output-suffix", "END"])
+        .assert()
+        .success()
+        .stdout(format!("BEGIN{mnemonic}END\n"));
+
+    // Hyphenated sentinels are values, not flags
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["entropy", mnemonic, "--quiet", "--output-prefix", "-----"])
+        .assert()
+        .success()
+        .stdout("-----00000000000000000000000000000000\n");
+
+    // Headers mode is left alone
+    Command::cargo_bin("bip39")
+        .unwrap()
+        .args(["from-entropy", "00000000000000000000000000000000"])
+        .args(["--output-prefix", "BEGIN"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("BEGIN").not());
+}
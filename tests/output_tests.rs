@@ -0,0 +1,79 @@
+use bip39_cli::output::Output;
+
+const PHRASE: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+fn render(quiet: bool, f: impl FnOnce(&mut Output<Vec<u8>>) -> std::io::Result<()>) -> String {
+    let mut out = Output::new(Vec::new(), quiet).framed("[", "]");
+    f(&mut out).unwrap();
+    String::from_utf8(out.into_inner()).unwrap()
+}
+
+#[test]
+fn test_generated_mnemonic_block_is_byte_for_byte() {
+    let rendered = render(false, |out| {
+        out.section_with_rule("Generated Mnemonic", 19)?;
+        out.field("Words", 12)?;
+        out.field("Entropy", "128 bits")?;
+        out.blank()?;
+        out.secret(PHRASE)
+    });
+
+    assert_eq!(
+        rendered,
+        format!(
+            "Generated Mnemonic\n{}\nWords: 12\nEntropy: 128 bits\n\n{PHRASE}\n",
+            "═".repeat(19)
+        )
+    );
+}
+
+#[test]
+fn test_section_rule_matches_display_width() {
+    let rendered = render(false, |out| out.section("🔬 Entropy Quality Analysis"));
+    assert_eq!(
+        rendered,
+        format!("🔬 Entropy Quality Analysis\n{}\n", "═".repeat(27))
+    );
+}
+
+#[test]
+fn test_quiet_keeps_only_framed_result_and_data() {
+    let rendered = render(true, |out| {
+        out.section("Mnemonic Validation")?;
+        out.field("Words", 12)?;
+        out.line("free text")?;
+        out.blank()?;
+        out.secret(PHRASE)?;
+        out.data("1: valid")
+    });
+
+    assert_eq!(rendered, format!("[{PHRASE}]\n1: valid\n"));
+}
+
+#[test]
+fn test_result_is_unframed_without_quiet() {
+    let rendered = render(false, |out| out.secret("00ff"));
+    assert_eq!(rendered, "00ff\n");
+}
+
+#[test]
+fn test_refused_secrets_fail_but_reports_still_print() {
+    let mut out = Output::new(Vec::new(), false).refusing_secrets(true);
+    out.field("Words", 12).unwrap();
+    assert!(out.secret(PHRASE).is_err());
+    assert!(out.secret_line(PHRASE).is_err());
+    assert!(out.raw(b"\x01").is_err());
+    out.data("valid").unwrap();
+
+    assert_eq!(
+        String::from_utf8(out.into_inner()).unwrap(),
+        "Words: 12\nvalid\n"
+    );
+}
+
+#[test]
+fn test_raw_has_no_newline() {
+    let rendered = render(true, |out| out.raw(b"\x01\x02"));
+    assert_eq!(rendered.as_bytes(), b"\x01\x02");
+}
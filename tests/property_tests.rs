@@ -149,8 +149,12 @@ mod unit_property_tests {
 
     #[test]
     fn test_find_invalid_words_empty_suggestions() {
-        let result =
-            security::find_invalid_words("validword invalidword", bip39::Language::English);
+        let result = security::find_invalid_words(
+            "validword invalidword",
+            bip39::Language::English,
+            security::DEFAULT_SUGGESTIONS,
+            security::DEFAULT_MAX_EDIT_DISTANCE,
+        );
 
         // Should find at least one invalid word (assuming these aren't in the word list)
         assert!(!result.is_empty());
@@ -0,0 +1,663 @@
+use std::collections::VecDeque;
+
+use bip39::Language;
+use bip39_cli::error::CliError;
+use bip39_cli::security::{self, PassphraseReader};
+
+#[test]
+fn test_normalize_collapses_whitespace() {
+    let normalized =
+        security::normalize_mnemonic("  abandon \t abandon\n\nabout  ", Language::English);
+    assert_eq!(normalized, "abandon abandon about");
+}
+
+#[test]
+fn test_normalize_folds_case_for_latin_scripts() {
+    let normalized = security::normalize_mnemonic("Abandon ABANDON About", Language::English);
+    assert_eq!(normalized, "abandon abandon about");
+}
+
+#[test]
+fn test_normalize_strips_decorations() {
+    let normalized =
+        security::normalize_mnemonic("1. abandon, 2) abandon; \"about\"", Language::English);
+    assert_eq!(normalized, "abandon abandon about");
+
+    let normalized = security::normalize_mnemonic("01.abandon 02:about", Language::English);
+    assert_eq!(normalized, "abandon about");
+}
+
+#[test]
+fn test_normalize_applies_nfkd() {
+    // Precomposed "é" (U+00E9) decomposes to "e" + combining acute (U+0301)
+    let normalized = security::normalize_mnemonic("\u{e9}lite", Language::French);
+    assert_eq!(normalized, "e\u{301}lite");
+
+    // Japanese ideographic space is treated as a separator
+    let normalized =
+        security::normalize_mnemonic("あいこくしん\u{3000}あいさつ", Language::Japanese);
+    assert_eq!(normalized.split_whitespace().count(), 2);
+}
+
+#[test]
+fn test_find_suspicious_characters() {
+    // Zero-width space, Cyrillic "а" (U+0430) and fullwidth "ｂ" (U+FF42)
+    let found = security::find_suspicious_characters(
+        "ab\u{200b}andon \u{430}bout \u{ff42}",
+        Language::English,
+    );
+    let flagged: Vec<(usize, char)> = found.iter().map(|s| (s.position, s.character)).collect();
+    assert_eq!(
+        flagged,
+        [(3, '\u{200b}'), (10, '\u{430}'), (16, '\u{ff42}')]
+    );
+    assert_eq!(found[0].reason, "invisible character");
+
+    // Accented letters are expected in the French list; tabs and newlines are plain spacing
+    assert!(security::find_suspicious_characters("\u{e9}lite", Language::French).is_empty());
+    assert!(security::find_suspicious_characters("abandon\tabout\n", Language::English).is_empty());
+}
+
+#[test]
+fn test_normalize_is_idempotent() {
+    let input = "1. Abandon  abandon, ABOUT";
+    let once = security::normalize_mnemonic(input, Language::English);
+    let twice = security::normalize_mnemonic(&once, Language::English);
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn test_four_letter_prefixes_are_unique_in_english() {
+    for word in Language::English.word_list() {
+        let prefix: String = word.chars().take(4).collect();
+        let matches = security::words_with_prefix(&prefix, Language::English);
+        assert!(
+            matches.len() <= 1 || word.len() < 4,
+            "prefix '{prefix}' matches {matches:?}"
+        );
+    }
+}
+
+#[test]
+fn test_words_with_prefix() {
+    assert_eq!(
+        security::words_with_prefix("ABAN", Language::English),
+        vec!["abandon"]
+    );
+    assert_eq!(
+        security::words_with_prefix("", Language::English).len(),
+        2048
+    );
+    assert!(security::words_with_prefix("xyz", Language::English).is_empty());
+}
+
+#[test]
+fn test_air_gap_status_is_sensible() {
+    let status = security::check_air_gapped_environment();
+
+    assert!((0.0..=1.0).contains(&status.score));
+    if status.is_air_gapped {
+        assert!(status.warnings.is_empty());
+        assert!(status.score > 0.8);
+    }
+    if status.warnings.is_empty() {
+        assert!((status.score - 1.0).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_deep_air_gap_check_with_online_probe() {
+    let status = security::check_air_gapped_environment_deep(|| true);
+
+    match status.connectivity {
+        security::Connectivity::Online => {
+            assert!(!status.is_air_gapped);
+            assert!(status.warnings.iter().any(|w| w.contains("online")));
+        }
+        security::Connectivity::NoInterfaces => {}
+        other => panic!("unexpected connectivity {other:?}"),
+    }
+}
+
+#[test]
+fn test_deep_air_gap_check_with_offline_probe() {
+    let status = security::check_air_gapped_environment_deep(|| false);
+
+    assert!(matches!(
+        status.connectivity,
+        security::Connectivity::InterfacesOffline | security::Connectivity::NoInterfaces
+    ));
+    // Interfaces without connectivity are not penalized
+    assert!(!status
+        .warnings
+        .iter()
+        .any(|w| w.contains("Network interfaces detected") || w.contains("Default network route")));
+}
+
+#[test]
+fn test_entropy_self_test_report_is_consistent() {
+    let report = security::entropy_self_test(50, 32);
+
+    assert_eq!(report.samples, 50);
+    assert!(report.min <= report.mean && report.mean <= report.max);
+    assert!(report.flagged <= report.samples);
+}
+
+#[test]
+fn test_entropy_self_test_mean_score_for_osrng() {
+    let report = security::entropy_self_test(1000, 32);
+
+    assert!(
+        report.mean > 0.9,
+        "mean score {:.3} over OsRng samples (min {:.3}, max {:.3})",
+        report.mean,
+        report.min,
+        report.max
+    );
+}
+
+#[test]
+fn test_repeating_pattern_rarely_flags_random_entropy() {
+    use rand::{rngs::OsRng, RngCore};
+
+    let mut buffer = [0u8; 32];
+    let flagged = (0..10_000)
+        .filter(|_| {
+            OsRng.fill_bytes(&mut buffer);
+            security::analyze_entropy_quality(&buffer)
+                .issues
+                .iter()
+                .any(|issue| issue.contains("repeating patterns"))
+        })
+        .count();
+
+    assert!(flagged < 10, "{flagged} of 10000 random samples flagged");
+}
+
+#[test]
+fn test_repeating_pattern_flags_abab_buffer() {
+    let quality = security::analyze_entropy_quality(&[0xA5, 0x3C].repeat(16));
+    assert!(quality
+        .issues
+        .iter()
+        .any(|issue| issue.contains("repeating patterns")));
+
+    let quality = security::analyze_entropy_quality(&[0x12, 0x9F, 0xE4, 0x70].repeat(8));
+    assert!(quality
+        .issues
+        .iter()
+        .any(|issue| issue.contains("repeating patterns")));
+}
+
+#[test]
+fn test_frequency_score_biased_vs_unbiased_short_input() {
+    // Random 32-byte entropy with 130 of 256 bits set
+    let unbiased =
+        hex::decode("7c1e9b04d3a65f28e1b7c04a9d3f6e8215a7c9e03b6d4f81a2e5c70b9d14f6a3").unwrap();
+    // Only the low nibble is ever set: 25% of bits
+    let biased =
+        hex::decode("0a030f0106040d020b07050e0c0908030f0a0601040d0e02070b050c09080301").unwrap();
+
+    let good = security::analyze_entropy_quality(&unbiased);
+    let bad = security::analyze_entropy_quality(&biased);
+
+    assert!(good.score > 0.9, "unbiased score {:.3}", good.score);
+    assert!(good.issues.is_empty(), "{:?}", good.issues);
+    assert!(bad.score < 0.5, "biased score {:.3}", bad.score);
+    assert!(bad
+        .issues
+        .iter()
+        .any(|issue| issue.contains("Poor byte distribution")));
+}
+
+#[test]
+fn test_frequency_score_biased_vs_unbiased_long_input() {
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    // Seeded, so the "random" input and its score are the same on every run
+    let mut unbiased = vec![0u8; 4096];
+    StdRng::seed_from_u64(1042).fill_bytes(&mut unbiased);
+    // Half the byte values never occur, but every bit position stays balanced
+    let biased: Vec<u8> = unbiased.iter().map(|&b| b & 0x7F | (b & 1) << 7).collect();
+
+    let good = security::analyze_entropy_quality(&unbiased);
+    let bad = security::analyze_entropy_quality(&biased);
+
+    assert!(good.score > 0.9, "unbiased score {:.3}", good.score);
+    assert!(bad
+        .issues
+        .iter()
+        .any(|issue| issue.contains("Poor byte distribution")));
+}
+
+/// Invalid words with the default suggestion count and edit distance
+fn invalid_words(mnemonic: &str, language: Language) -> Vec<(usize, String, Vec<String>)> {
+    security::find_invalid_words(
+        mnemonic,
+        language,
+        security::DEFAULT_SUGGESTIONS,
+        security::DEFAULT_MAX_EDIT_DISTANCE,
+    )
+}
+
+#[test]
+fn test_suggestions_use_edit_distance_for_french() {
+    let mnemonic = security::normalize_mnemonic("abeile", Language::French);
+    let invalid = invalid_words(&mnemonic, Language::French);
+
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].2.first().map(String::as_str), Some("abeille"));
+}
+
+#[test]
+fn test_suggestions_compare_japanese_readings() {
+    // さ with a stray voicing mark: one edit once NFKD splits off the ゛
+    let mnemonic = security::normalize_mnemonic("あいざつ", Language::Japanese);
+    let invalid = invalid_words(&mnemonic, Language::Japanese);
+
+    assert_eq!(invalid.len(), 1);
+    let expected = security::normalize_mnemonic("あいさつ", Language::Japanese);
+    assert_eq!(invalid[0].2.first(), Some(&expected));
+}
+
+#[test]
+fn test_suggestions_map_between_chinese_scripts() {
+    // 這 is the traditional form of 这 (index 9 in both lists)
+    let invalid = invalid_words("這", Language::SimplifiedChinese);
+    assert_eq!(invalid[0].2, vec!["这".to_string()]);
+
+    let invalid = invalid_words("这", Language::TraditionalChinese);
+    assert_eq!(invalid[0].2, vec!["這".to_string()]);
+
+    // An unrelated character gets no suggestions rather than 2048 of them
+    let invalid = invalid_words("猫", Language::SimplifiedChinese);
+    assert!(invalid[0].2.is_empty());
+}
+
+#[test]
+fn test_suggestions_ranked_by_distance_then_alphabetically() {
+    let invalid = invalid_words("abandonn", Language::English);
+    assert_eq!(invalid[0].2.first().map(String::as_str), Some("abandon"));
+
+    // Distance 1: able, cable, table; distance 2: eagle, enable, lab, ...
+    let invalid = security::find_invalid_words(
+        "lable",
+        Language::English,
+        5,
+        security::DEFAULT_MAX_EDIT_DISTANCE,
+    );
+    assert_eq!(invalid[0].2, ["able", "cable", "table", "eagle", "enable"]);
+
+    let invalid = security::find_invalid_words(
+        "lable",
+        Language::English,
+        0,
+        security::DEFAULT_MAX_EDIT_DISTANCE,
+    );
+    assert!(invalid[0].2.is_empty());
+}
+
+#[test]
+fn test_suggestions_are_ordered_and_unique() {
+    for (word, language) in [
+        ("lable", Language::English),
+        ("abndn", Language::English),
+        ("ABANDONN", Language::English),
+        ("abeile", Language::French),
+        ("abaco", Language::Spanish),
+    ] {
+        let invalid = security::find_invalid_words(word, language, 50, 3);
+        let suggestions = &invalid[0].2;
+        assert!(!suggestions.is_empty(), "{word}");
+
+        let target = word.to_lowercase();
+        let keys: Vec<(usize, &str)> = suggestions
+            .iter()
+            .map(|s| (security::edit_distance(&target, s), s.as_str()))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(keys, sorted, "{word}: not ordered or has duplicates");
+    }
+}
+
+#[test]
+fn test_suggestions_respect_max_edit_distance() {
+    // "abandon" is three edits away from "abndnd" (two insertions, one substitution)
+    let invalid = security::find_invalid_words("abndnd", Language::English, 3, 1);
+    assert!(invalid[0].2.is_empty());
+
+    let invalid = security::find_invalid_words("abndnd", Language::English, 3, 3);
+    assert_eq!(invalid[0].2.first().map(String::as_str), Some("abandon"));
+}
+
+/// In-memory stand-in for the system clipboard that records every write
+#[derive(Default)]
+struct FakeClipboard {
+    text: Option<String>,
+    writes: Vec<String>,
+}
+
+impl bip39_cli::clipboard::ClipboardText for FakeClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<(), bip39_cli::CliError> {
+        self.text = Some(text.to_string());
+        self.writes.push(text.to_string());
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), bip39_cli::CliError> {
+        self.text = None;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_clipboard_cleared_after_timeout() {
+    use bip39_cli::clipboard::{clear_after, ClipboardText};
+    use std::time::{Duration, Instant};
+
+    let secret = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    let mut clipboard = FakeClipboard::default();
+    clipboard.set_text(secret).unwrap();
+
+    let start = Instant::now();
+    let timeout = Duration::from_millis(50);
+    assert!(clear_after(&mut clipboard, secret, timeout).unwrap());
+    assert!(start.elapsed() >= timeout);
+
+    // The secret was overwritten with blanks of the same length before clearing
+    assert_eq!(clipboard.text, None);
+    assert_eq!(clipboard.writes.last().unwrap(), &" ".repeat(secret.len()));
+}
+
+#[test]
+fn test_clipboard_left_alone_if_changed() {
+    use bip39_cli::clipboard::{clear_after, ClipboardText};
+    use std::time::Duration;
+
+    let mut clipboard = FakeClipboard::default();
+    clipboard.set_text("copied by the user later").unwrap();
+
+    let cleared = clear_after(&mut clipboard, "secret phrase", Duration::from_millis(1)).unwrap();
+    assert!(!cleared);
+    assert_eq!(clipboard.text.as_deref(), Some("copied by the user later"));
+    assert_eq!(clipboard.writes.len(), 1);
+}
+
+#[test]
+fn test_detect_language() {
+    let french = "implorer visage sonnette voyage véloce pourpre volaille tribunal implorer visage sonnette voyelle";
+    assert_eq!(security::detect_language(french).unwrap(), Language::French);
+    // Case and decorations are normalized per candidate language
+    assert_eq!(
+        security::detect_language("1. ABANDON abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap(),
+        Language::English
+    );
+
+    // Every word is in both the English and French lists; only English has a valid checksum
+    let shared =
+        "abandon amateur angle animal aspect badge bicycle bonus brave canal capable abandon";
+    assert_eq!(
+        security::detect_language(shared).unwrap(),
+        Language::English
+    );
+
+    match security::detect_language("abandon abandon zzz") {
+        Err(bip39_cli::CliError::LanguageDetectionFailed { candidates }) => {
+            assert!(candidates.is_empty());
+        }
+        other => panic!("expected a detection failure, got {other:?}"),
+    }
+}
+
+/// Serializes the tests that lock memory, so they can compare the process-wide count
+#[cfg(unix)]
+static MEMORY_LOCK_TESTS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(unix)]
+#[test]
+fn test_secret_buffer_locks_or_falls_back() {
+    use bip39_cli::memory::SecretBuffer;
+
+    let _guard = MEMORY_LOCK_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut buffer = SecretBuffer::new(64, true);
+    assert_eq!(&buffer[..], &[0u8; 64][..]);
+    buffer[..4].copy_from_slice(b"seed");
+    assert_eq!(&buffer[..4], b"seed");
+
+    // Unprivileged users may have RLIMIT_MEMLOCK too low to lock anything;
+    // the buffer then works unlocked instead of failing
+    let copy = SecretBuffer::from_slice(&buffer, true);
+    assert_eq!(copy.is_locked(), buffer.is_locked());
+    assert_eq!(&copy[..], &buffer[..]);
+
+    assert!(!SecretBuffer::new(16, false).is_locked());
+    assert!(SecretBuffer::new(0, true).is_locked());
+}
+
+/// Locked memory of this process in kB, as reported by the kernel
+#[cfg(target_os = "linux")]
+fn locked_kb() -> u64 {
+    let status = std::fs::read_to_string("/proc/self/status").unwrap();
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmLck:"))
+        .and_then(|rest| rest.trim().trim_end_matches("kB").trim().parse().ok())
+        .unwrap()
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_secret_buffer_unlocks_on_drop() {
+    use bip39_cli::memory::SecretBuffer;
+
+    // Large enough that other tests' small locked buffers cannot hide the change, but
+    // under malloc's mmap threshold: an mmap'd buffer is unmapped on free, which
+    // would release the lock even if munlock were never called
+    const LEN_KB: u64 = 64;
+    let _guard = MEMORY_LOCK_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+    let before = locked_kb();
+    let buffer = SecretBuffer::new(LEN_KB as usize * 1024, true);
+    if !buffer.is_locked() {
+        return; // RLIMIT_MEMLOCK too low here; nothing was locked to release
+    }
+    let during = locked_kb();
+    assert!(during >= before + LEN_KB, "{before} kB -> {during} kB");
+
+    drop(buffer);
+    let after = locked_kb();
+    assert!(
+        after + LEN_KB <= during,
+        "{during} kB -> {after} kB after drop"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_secret_buffers_do_not_share_locked_pages() {
+    use bip39_cli::memory::{SecretBuffer, SecretString};
+
+    let _guard = MEMORY_LOCK_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+    let before = locked_kb();
+    // Small secrets would share a heap page; munlock on one would unlock the other
+    let seed = SecretBuffer::new(64, true);
+    let passphrase = SecretString::from_string("TREZOR".to_string(), true);
+    if !seed.is_locked() || !passphrase.is_locked() {
+        return; // RLIMIT_MEMLOCK too low here
+    }
+    assert_eq!(&*passphrase, "TREZOR");
+    let during = locked_kb();
+    assert!(during > before, "{before} kB -> {during} kB");
+
+    drop(seed);
+    let after = locked_kb();
+    assert!(
+        after > before,
+        "passphrase unlocked by dropping the seed: {before} kB -> {during} kB -> {after} kB"
+    );
+}
+
+#[test]
+fn test_word_list_digests() {
+    // sha256sum of english.txt in the BIP39 repository
+    assert_eq!(
+        security::word_list_sha256(Language::English),
+        "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda"
+    );
+    assert!(security::word_list_mismatches(&security::WORD_LIST_SHA256).is_empty());
+    assert_eq!(security::WORD_LIST_SHA256.len(), Language::ALL.len());
+
+    let tampered = [
+        (Language::English, "00".repeat(32)),
+        (
+            Language::French,
+            security::word_list_sha256(Language::French),
+        ),
+    ];
+    let tampered: Vec<(Language, &str)> = tampered
+        .iter()
+        .map(|(language, digest)| (*language, digest.as_str()))
+        .collect();
+    assert_eq!(
+        security::word_list_mismatches(&tampered),
+        [Language::English]
+    );
+}
+
+/// Stands in for the terminal, answering prompts from a fixed list
+struct CannedReader {
+    entries: VecDeque<String>,
+    prompts: Vec<String>,
+}
+
+impl CannedReader {
+    fn new(entries: &[&str]) -> Self {
+        Self {
+            entries: entries.iter().map(ToString::to_string).collect(),
+            prompts: Vec::new(),
+        }
+    }
+}
+
+impl PassphraseReader for CannedReader {
+    fn read_hidden(&mut self, prompt: &str) -> Result<String, CliError> {
+        self.prompts.push(prompt.to_string());
+        Ok(self.entries.pop_front().expect("prompted too often"))
+    }
+}
+
+#[test]
+fn test_read_confirmed_retries_until_entries_match() {
+    let mut reader = CannedReader::new(&["hunter2", "hunter2"]);
+    let matched = security::read_confirmed(&mut reader, "Passphrase:");
+    assert_eq!(matched.unwrap(), "hunter2");
+    assert_eq!(
+        reader.prompts,
+        ["Passphrase:", "Enter it again to confirm:"]
+    );
+
+    // A typo in either entry re-prompts for both
+    let mut reader = CannedReader::new(&[
+        "hunter2", "hunter3", "Hunter2", "hunter2", "hunter2", "hunter2",
+    ]);
+    let retried = security::read_confirmed(&mut reader, "Passphrase:");
+    assert_eq!(retried.unwrap(), "hunter2");
+    assert!(reader.entries.is_empty());
+
+    // Entries differing only in length never match
+    let mismatched = ["abc", "abcd"].repeat(security::MAX_CONFIRM_ATTEMPTS);
+    let error =
+        security::read_confirmed(&mut CannedReader::new(&mismatched), "Passphrase:").unwrap_err();
+    assert_eq!(error.exit_code(), 6);
+    assert!(error.to_string().contains("did not match in 3 attempts"));
+}
+
+#[test]
+fn test_secure_mnemonic_input_rejects_invalid_word() {
+    let mut reader = CannedReader::new(&[
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandn about",
+    ]);
+    let error =
+        security::secure_mnemonic_input(&mut reader, "Enter mnemonic:", Language::English, 3, 2)
+            .unwrap_err();
+
+    match error {
+        CliError::InvalidWord {
+            word,
+            position,
+            suggestions,
+        } => {
+            assert_eq!(word, "abandn");
+            assert_eq!(position, 11);
+            assert!(suggestions.contains(&"abandon".to_string()));
+        }
+        other => panic!("expected InvalidWord, got {other:?}"),
+    }
+    assert_eq!(reader.prompts, ["Enter mnemonic:"]);
+}
+
+#[test]
+fn test_secure_mnemonic_input_normalizes_valid_input() {
+    let mut reader = CannedReader::new(&[
+        "  Abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ABOUT ",
+    ]);
+    let mnemonic =
+        security::secure_mnemonic_input(&mut reader, "Enter mnemonic:", Language::English, 3, 2)
+            .unwrap();
+    assert_eq!(
+        mnemonic,
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    );
+}
+
+#[test]
+fn test_mnemonic_commitment_is_keyed_by_salt() {
+    let phrase =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    // Same value as `openssl dgst -sha256 -mac HMAC -macopt hexkey:0102`
+    assert_eq!(
+        hex::encode(security::mnemonic_commitment(&[1, 2], phrase)),
+        "b6a803aeeaec77d2daa000b1043bb5e4aa53ccbb3b49067886ee1fba9f763694"
+    );
+    assert_eq!(
+        security::mnemonic_commitment(&[1, 2], phrase),
+        security::mnemonic_commitment(&[1, 2], phrase)
+    );
+    assert_ne!(
+        security::mnemonic_commitment(&[1, 2], phrase),
+        security::mnemonic_commitment(&[1, 3], phrase)
+    );
+}
+
+#[test]
+fn test_dice_round_trip() {
+    assert_eq!(security::dice_roll_count(16), 50);
+    assert_eq!(security::dice_roll_count(32), 100);
+
+    // 2 = 0b10 is "13" in base 6 plus one per roll, after 48 zero digits
+    let mut two = [0u8; 16];
+    two[15] = 2;
+    let rolls = security::entropy_to_dice(&two);
+    assert_eq!(rolls.len(), 50);
+    assert!(rolls[..49].iter().all(|&roll| roll == 1));
+    assert_eq!(rolls[49], 3);
+
+    for entropy in [[0u8; 16].to_vec(), [0xff; 16].to_vec(), (0..32).collect()] {
+        let rolls = security::entropy_to_dice(&entropy);
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+        assert_eq!(
+            security::dice_to_entropy(&rolls, entropy.len()),
+            Some(entropy)
+        );
+    }
+
+    // A 7 is not a die face, and 50 sixes exceed 128 bits
+    assert_eq!(security::dice_to_entropy(&[1, 7], 16), None);
+    assert_eq!(security::dice_to_entropy(&[6; 50], 16), None);
+}